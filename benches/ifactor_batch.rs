@@ -0,0 +1,41 @@
+//! Compares `ifactor_batch` with a loop calling `Gen::ifactor` on each value.
+//!
+//! Run with `cargo bench --bench ifactor_batch`.
+
+use std::{hint::black_box, time::Instant};
+
+use giacrs::{
+    context::{release_globals, Context},
+    gen::Gen,
+    integers::ifactor_batch,
+    GiacError,
+};
+
+const RUNS: u32 = 10;
+
+fn main() -> Result<(), GiacError> {
+    let ctx = Context::new();
+
+    for size in [100, 1000, 10000] {
+        let values: Vec<Gen> = (0..size).map(|i| Gen::from(1_000_000 + i)).collect();
+
+        let start = Instant::now();
+        for _ in 0..RUNS {
+            for value in &values {
+                black_box(value.ifactor(&ctx)?);
+            }
+        }
+        let looped = start.elapsed() / RUNS;
+
+        let start = Instant::now();
+        for _ in 0..RUNS {
+            black_box(ifactor_batch(&values, &ctx)?);
+        }
+        let batched = start.elapsed() / RUNS;
+
+        println!("{size:>6} values: loop {looped:>12?}, batch {batched:>12?}");
+    }
+
+    release_globals();
+    Ok(())
+}
@@ -0,0 +1,242 @@
+//! Calculus: series, residues, sums
+
+use giacrs_internals::ffi_safe_call;
+
+use crate::{context::Context, ffi, gen::Gen, GiacError};
+
+impl Gen {
+    /// Computes the Laurent series expansion of the expression in `var` around `at`, up to `var^order`.
+    ///
+    /// Unlike a Taylor expansion, the expansion point may be a pole: the principal part is represented
+    /// by negative powers of `var-at`, printed as quotients like `-1/x`. The remainder is represented by an `order_size` term.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let f = Gen::from_str("1/(x*(x-1))", &ctx)?;
+    ///
+    /// let s = f.laurent(&x, &0.into(), 3, &ctx)?;
+    /// assert!(s.to_string().contains("-1/x"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn laurent(
+        &self,
+        var: &Gen,
+        at: &Gen,
+        order: u32,
+        ctx: &Context,
+    ) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_laurent(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                at.as_gen_ref(),
+                order,
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Samples the expression, as a function of `var`, at `samples` evenly spaced points of `[from, to]`, including both bounds,
+    /// and returns the points `(x, y)` ready to be plotted.
+    ///
+    /// The values are computed with floating point numbers. Points where the evaluation fails, or gives a value which
+    /// isn't a finite real number (a pole, a complex value, an undefined expression...) are kept with a `NaN` `y`,
+    /// so they can be rendered as gaps. Discontinuities between two samples are not detected.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// let points = Gen::from_str("sin(x)", &ctx)?.plot_points(&x, 0.0, 2.0 * std::f64::consts::PI, 100, &ctx)?;
+    /// assert_eq!(100, points.len());
+    /// assert!(points.iter().all(|(x, y)| (y - x.sin()).abs() < 1e-12));
+    ///
+    /// let points = Gen::from_str("ln(x)", &ctx)?.plot_points(&x, -1.0, 1.0, 3, &ctx)?;
+    /// assert!(points[0].1.is_nan() && points[1].1.is_nan());
+    /// assert_eq!((1.0, 0.0), points[2]);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn plot_points(
+        &self,
+        var: &Gen,
+        from: f64,
+        to: f64,
+        samples: usize,
+        ctx: &Context,
+    ) -> Result<Vec<(f64, f64)>, GiacError> {
+        let mut xs = vec![0.0; samples];
+        let mut ys = vec![0.0; samples];
+        let error = unsafe {
+            ffi::giacrs_gen_plot_points(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                from,
+                to,
+                samples,
+                xs.as_mut_ptr(),
+                ys.as_mut_ptr(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(xs.into_iter().zip(ys).collect())
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Computes the residue of the expression in `var` at `pole`.
+    ///
+    /// Poles of any order are handled, the residue being the coefficient of `1/(var-pole)` in the Laurent expansion.
+    /// When giac can't compute it, the unevaluated `residue(...)` expression is returned.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let f = Gen::from_str("1/(x^2+1)", &ctx)?;
+    /// let i = Gen::from_str("i", &ctx)?;
+    ///
+    /// let r = f.residue(&x, &i, &ctx)?;
+    /// assert!((r - &Gen::from_str("-i/2", &ctx)?).simplify(&ctx)?.is_zero(&ctx)?);
+    /// // Pole of order 2
+    /// let g = Gen::from_str("exp(x)/x^2", &ctx)?;
+    /// assert_eq!(1, g.residue(&x, &0.into(), &ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn residue(&self, var: &Gen, pole: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_residue(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                pole.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Computes the sum of the expression for `index` going from `lower` to `upper`, both included.
+    /// The bounds may be symbolic, and `upper` may be `+infinity` when giac can evaluate the series.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let k = Gen::from_str("k", &ctx)?;
+    /// let n = Gen::from_str("n", &ctx)?;
+    ///
+    /// let s = k.sum(&k, &1.into(), &n, &ctx)?;
+    /// let expected = Gen::from_str("n*(n+1)/2", &ctx)?;
+    /// assert!((s - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    ///
+    /// let f = Gen::from_str("1/k^2", &ctx)?;
+    /// let s = f.sum(&k, &1.into(), &Gen::infinity(&ctx), &ctx)?;
+    /// assert_eq!("pi^2/6", s.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node99.html>
+    pub fn sum(
+        &self,
+        index: &Gen,
+        lower: &Gen,
+        upper: &Gen,
+        ctx: &Context,
+    ) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_sum(
+                self.as_gen_ref(),
+                index.as_gen_ref(),
+                lower.as_gen_ref(),
+                upper.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Computes the product of the expression for `index` going from `lower` to `upper`, both included.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let k = Gen::from_str("k", &ctx)?;
+    ///
+    /// assert_eq!(120, k.product(&k, &1.into(), &5.into(), &ctx)?.to_int()?);
+    ///
+    /// let f = Gen::from_str("(k+1)/k", &ctx)?;
+    /// let p = f.product(&k, &1.into(), &Gen::from_str("n", &ctx)?, &ctx)?;
+    /// assert_eq!("n+1", p.simplify(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn product(
+        &self,
+        index: &Gen,
+        lower: &Gen,
+        upper: &Gen,
+        ctx: &Context,
+    ) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_product(
+                self.as_gen_ref(),
+                index.as_gen_ref(),
+                lower.as_gen_ref(),
+                upper.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+}
+
+/// Estimates the limit of a slowly converging sequence with Richardson extrapolation.
+///
+/// `terms` is the vector `[S_1, S_2, ..., S_N]` of the first values of the sequence, like the partial sums of a series.
+/// The sequence is assumed to behave like `S_n = L + a_1/n + a_2/n^2 + ...`, which holds for many series
+/// with terms that are rational functions of `n`. The extrapolation of order `m` combines the last `m+1` terms
+/// to eliminate the first `m` error terms, with `m = min(N-1, 8)` to limit the amplification of rounding errors.
+/// At least 2 terms are required, exact terms give an exact result.
+/// ```
+/// use giacrs::calculus::accelerate_sequence;
+/// use giacrs::context::Context;
+/// use giacrs::gen;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// // Partial sums of sum(1/n^2), which converges to pi^2/6 with an error of about 1/n
+/// let terms = Gen::from_str("[seq(sum(1/k^2,k,1,n),n,1,12)]", &ctx)?;
+///
+/// let limit = accelerate_sequence(&terms, &ctx)?;
+/// assert_eq!(1, gen!("abs({}-pi^2/6)<1e-8", limit; ctx)?.to_int()?);
+/// assert!(accelerate_sequence(&Gen::from_str("[1]", &ctx)?, &ctx).is_err());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn accelerate_sequence(terms: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_accelerate_sequence(
+            terms.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
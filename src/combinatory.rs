@@ -57,4 +57,107 @@ impl Gen {
     pub fn rand(&self, ctx: &Context) -> Result<Self, GiacError> {
         ffi_safe_call! {ffi::giacrs_gen_rand(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
+
+    /// Returns a random integer p such that `lower <= p < upper`.
+    /// Returns an error if the bounds aren't integers or if `lower >= upper`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let rand = Gen::rand_range(&(-5).into(), &5.into(), &ctx)?.to_int()?;
+    ///
+    /// assert!(-5 <= rand && rand < 5);
+    /// assert!(Gen::rand_range(&3.into(), &3.into(), &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn rand_range(lower: &Gen, upper: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {ffi::giacrs_gen_rand_range(lower.as_gen_ref(), upper.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns a `rows`x`cols` matrix of random integers between -99 and 99
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let m = Gen::random_matrix(2, 3, &ctx)?;
+    ///
+    /// let rows: Vec<Gen> = m.into_iter().collect();
+    ///
+    /// assert_eq!(2, rows.len());
+    /// for row in &rows {
+    ///     assert_eq!(3, row.into_iter().count());
+    /// }
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node59.html>
+    pub fn random_matrix(rows: usize, cols: usize, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {ffi::giacrs_gen_random_matrix(rows, cols, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+}
+
+/// Draws a sample from the binomial distribution with `n` trials of probability `p`
+/// ```
+/// use giacrs::combinatory::randbinomial;
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let k = randbinomial(&10.into(), &Gen::from_str("1/2", &ctx)?, &ctx)?.to_int()?;
+///
+/// assert!(0 <= k && k <= 10);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+/// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node59.html>
+pub fn randbinomial(n: &Gen, p: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_randbinomial(
+            n.as_gen_ref(),
+            p.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Draws a sample from the normal distribution of mean `mean` and standard deviation `stddev`
+/// ```
+/// use giacrs::combinatory::randnormal;
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::types::GenType;
+///
+/// let ctx = Context::new();
+/// let x = randnormal(&0.into(), &1.into(), &ctx)?;
+///
+/// assert_eq!(GenType::Double, x.get_type());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+/// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node59.html>
+pub fn randnormal(mean: &Gen, stddev: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_randnormal(
+            mean.as_gen_ref(),
+            stddev.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
 }
@@ -1,43 +1,81 @@
 //! A giac `Context` to keep variables
 
-use std::sync::LazyLock;
+use std::{
+    ffi::CString,
+    os::raw::c_int,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        LazyLock, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use crate::{
     ffi::{self, giacrs_init_global_context},
     gen::Gen,
     support::GiacError,
+    types::FunctionProperty,
 };
 
 pub(crate) enum GiacContext {}
 pub(crate) type GiacContextRef = *const GiacContext;
 
+/// A property stored by [Context::assume_function], kept as C strings so that the context doesn't own any [Gen]
+pub(crate) struct AssumedFunction {
+    pub(crate) name: CString,
+    pub(crate) kind: c_int,
+    /// Printed period, parsed again by giac when simplifying
+    pub(crate) period: Option<CString>,
+}
+
 /// A giac context used to manage multiple sessions, with differents variables.
-pub struct Context(GiacContextRef);
+pub struct Context {
+    ptr: GiacContextRef,
+    function_properties: Vec<AssumedFunction>,
+}
 
 unsafe impl Sync for Context {}
 unsafe impl Send for Context {}
 
+/// Number of [Context::eval_with_timeout] calls whose deadline passed but which haven't returned yet.
+/// Giac's interruption flag is global, so it's only cleared once all of them have stopped.
+static PENDING_INTERRUPTS: Mutex<usize> = Mutex::new(0);
+
 /// Pointer to the global giac context
 #[allow(unused)]
 pub static GLOBAL_CONTEXT: LazyLock<Context> = LazyLock::new(|| {
     unsafe { giacrs_init_global_context() };
-    Context(unsafe { ffi::giacrs_global_context })
+    Context {
+        ptr: unsafe { ffi::giacrs_global_context },
+        function_properties: Vec::new(),
+    }
 });
 
 impl Context {
     /// Internal giac pointer
     pub(crate) unsafe fn as_context_ref(&self) -> GiacContextRef {
-        self.0
+        self.ptr
+    }
+
+    /// Properties assumed with [Context::assume_function]
+    pub(crate) fn function_properties(&self) -> &[AssumedFunction] {
+        &self.function_properties
     }
 
     /// Init a new giac context.
     ///
     /// Each context has it owns variables, it's usefull to manage multiple sessions at the same time, like in XCAS.
     pub fn new() -> Self {
-        Self(unsafe { ffi::giacrs_new_context() })
+        Self {
+            ptr: unsafe { ffi::giacrs_new_context() },
+            function_properties: Vec::new(),
+        }
     }
 
     /// Evaluates a string to an expression in the current context.
+    ///
+    /// The string is parsed then fully evaluated, so assigned variables are replaced by their values.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
@@ -52,6 +90,333 @@ impl Context {
     pub fn eval(&self, str: &str) -> Result<Gen, GiacError> {
         Gen::from_str(str, &self)
     }
+
+    /// Evaluates a string to an expression like [Context::eval], interrupting the computation if it takes longer than `timeout`.
+    /// In this case, [GiacError::Interrupted] is returned. Other errors are returned as is, even if they happen after the deadline.
+    ///
+    /// A watchdog thread raises giac's interruption flag when the deadline passes, so the computation actually stops.
+    /// This flag is global to giac: computations running at the same time in other threads, even with other contexts, are interrupted too.
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::GiacError;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let timeout = Duration::from_millis(100);
+    /// assert_eq!(2, ctx.eval_with_timeout("1+1", timeout)?.to_int()?);
+    /// assert!(matches!(
+    ///     ctx.eval_with_timeout("while(true){}", timeout),
+    ///     Err(GiacError::Interrupted)
+    /// ));
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    ///
+    /// The flag stays raised until every timed out computation has stopped, so concurrent calls all respect their deadline:
+    /// ```
+    /// use std::{thread, time::Duration};
+    ///
+    /// use giacrs::context::Context;
+    ///
+    /// thread::scope(|s| {
+    ///     let handles: Vec<_> = (1..=4)
+    ///         .map(|i| {
+    ///             s.spawn(move || {
+    ///                 let ctx = Context::new();
+    ///                 ctx.eval_with_timeout("while(true){}", Duration::from_millis(50 * i))
+    ///             })
+    ///         })
+    ///         .collect();
+    ///     for handle in handles {
+    ///         // Calls stopped by an earlier deadline return giac's error instead of Interrupted
+    ///         assert!(handle.join().unwrap().is_err());
+    ///     }
+    /// });
+    /// ```
+    pub fn eval_with_timeout(&self, str: &str, timeout: Duration) -> Result<Gen, GiacError> {
+        let (done, done_receiver) = mpsc::channel::<()>();
+        let watchdog = thread::spawn(move || {
+            let timed_out = done_receiver.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout);
+            if timed_out {
+                let mut pending = PENDING_INTERRUPTS.lock().unwrap();
+                *pending += 1;
+                unsafe { ffi::giacrs_interrupt() };
+            }
+            timed_out
+        });
+        let result = self.eval(str);
+        // Stop the watchdog, it may still have timed out in the meantime
+        drop(done);
+        let timed_out = watchdog.join().unwrap();
+        let mut interrupted = false;
+        if timed_out {
+            let mut pending = PENDING_INTERRUPTS.lock().unwrap();
+            // The computation may have failed on its own before giac noticed the flag
+            interrupted = unsafe { ffi::giacrs_is_interrupted() };
+            *pending -= 1;
+            // Other timed out computations may not have noticed the flag yet
+            if *pending == 0 {
+                unsafe { ffi::giacrs_clear_interrupt() };
+            }
+        }
+        match result {
+            Err(_) if interrupted => Err(GiacError::Interrupted),
+            result => result,
+        }
+    }
+
+    /// Evaluates an already built expression in the current context, replacing assigned variables by their values.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// // Built before a is assigned, so a isn't replaced
+    /// let a = Gen::from_str("a", &ctx)?;
+    /// let e = Gen::from_str("a+1", &ctx)?;
+    ///
+    /// ctx.eval("a:=3")?;
+    /// assert_eq!(3, ctx.eval_gen(&a)?.to_int()?);
+    /// assert_eq!(4, ctx.eval_gen(&e)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn eval_gen(&self, expr: &Gen) -> Result<Gen, GiacError> {
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_context_eval_gen(
+                expr.as_gen_ref(),
+                self.as_context_ref(),
+                result.as_gen_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Evaluates a string to an expression, with temporary values for some variables.
+    ///
+    /// The variables of `locals` are assigned in the context, then the expression is evaluated,
+    /// then the variables are restored to their previous values or assumptions, or unassigned if they had none.
+    /// So unlike [Context::assign], the context is left unchanged, even if the evaluation fails.
+    /// While evaluating, local values shadow the variables already assigned, including when they are referenced indirectly,
+    /// by another variable or by a function.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let sum = ctx.eval_with("a+b", &[("a", Gen::from(1)), ("b", Gen::from(2))])?;
+    /// assert_eq!(3, sum.to_int()?);
+    /// // a and b are still undefined
+    /// assert_eq!("a+b", ctx.eval("a+b")?.to_string());
+    ///
+    /// // Indirect references see the local values
+    /// // Assigned while a is undefined, so c keeps referencing a
+    /// ctx.eval("c:=a+1")?;
+    /// ctx.eval("f(x):=x+a")?;
+    /// ctx.eval("a:=5")?;
+    /// assert_eq!(11, ctx.eval_with("c", &[("a", Gen::from(10))])?.to_int()?);
+    /// assert_eq!(12, ctx.eval_with("f(2)", &[("a", Gen::from(10))])?.to_int()?);
+    /// // a is restored
+    /// assert_eq!(5, ctx.eval("a")?.to_int()?);
+    ///
+    /// // Assumptions are restored too
+    /// ctx.eval("assume(d>0)")?;
+    /// assert_eq!(-2, ctx.eval_with("d+1", &[("d", Gen::from(-3))])?.to_int()?);
+    /// assert_eq!("d", ctx.eval("sqrt(d^2)")?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn eval_with(&self, src: &str, locals: &[(&str, Gen)]) -> Result<Gen, GiacError> {
+        let src = CString::new(src).unwrap();
+        let names: Vec<CString> = locals
+            .iter()
+            .map(|(name, _)| CString::new(*name).unwrap())
+            .collect();
+        let names: Vec<_> = names.iter().map(|name| name.as_ptr()).collect();
+        let values: Vec<_> = locals
+            .iter()
+            .map(|(_, value)| unsafe { value.as_gen_ref() })
+            .collect();
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_context_eval_with(
+                src.as_ptr(),
+                names.as_ptr(),
+                values.as_ptr(),
+                locals.len(),
+                self.as_context_ref(),
+                result.as_gen_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Assigns `value` to the variable `name` in the context, like `name:=value` without parsing a string.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.assign("a", &Gen::from(5))?;
+    /// assert_eq!(25, ctx.eval("a^2")?.to_int()?);
+    /// assert!(ctx.assign("1+", &Gen::from(5)).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn assign(&mut self, name: &str, value: &Gen) -> Result<(), GiacError> {
+        let name = CString::new(name).unwrap();
+        let error = unsafe {
+            ffi::giacrs_context_assign(name.as_ptr(), value.as_gen_ref(), self.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(())
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the value of the variable `name` in the context.
+    /// If the variable isn't assigned, the variable itself is returned.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// let value = Gen::from_str("x+1", &ctx)?;
+    /// ctx.assign("a", &value)?;
+    /// assert_eq!("x+1", ctx.get("a")?.to_string());
+    /// assert_eq!("b", ctx.get("b")?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn get(&self, name: &str) -> Result<Gen, GiacError> {
+        let name = CString::new(name).unwrap();
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_context_get(name.as_ptr(), self.as_context_ref(), result.as_gen_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Unassigns the variable `name` in the context, like giac's `purge`.
+    /// The variable then evaluates to itself.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.assign("a", &Gen::from(5))?;
+    /// ctx.purge("a")?;
+    /// assert_eq!("a", ctx.eval("a")?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn purge(&mut self, name: &str) -> Result<(), GiacError> {
+        let name = CString::new(name).unwrap();
+        let error = unsafe { ffi::giacrs_context_purge(name.as_ptr(), self.as_context_ref()) };
+        if error == std::ptr::null() {
+            Ok(())
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Assumes a property of the function `name`, used by [Gen::simplify] to rewrite its calls.
+    ///
+    /// Giac's `assume` only handles variables, so the properties are applied by giacrs before simplifying:
+    /// - [FunctionProperty::Even] rewrites `f(-x)` as `f(x)`,
+    /// - [FunctionProperty::Odd] rewrites `f(-x)` as `-f(x)`,
+    /// - [FunctionProperty::Periodic] removes from the argument the terms which are integer multiples of the period,
+    ///   so `f(x+2*T)` becomes `f(x)`.
+    ///
+    /// Only calls with a single argument are rewritten. An argument is considered negative when it's written with
+    /// a leading minus sign, like `-x`, `-2*x` or `-x+1`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::FunctionProperty;
+    ///
+    /// let mut ctx = Context::new();
+    /// ctx.assume_function("f", FunctionProperty::Even)?;
+    /// ctx.assume_function("g", FunctionProperty::Odd)?;
+    /// ctx.assume_function("h", FunctionProperty::Periodic(Gen::from_str("2*pi", &ctx)?))?;
+    ///
+    /// assert_eq!("f(x)", ctx.eval("f(-x)")?.simplify(&ctx)?.to_string());
+    /// assert_eq!(0, ctx.eval("f(x)-f(-x)")?.simplify(&ctx)?.to_int()?);
+    /// assert_eq!("-g(x)", ctx.eval("g(-x)")?.simplify(&ctx)?.to_string());
+    /// assert_eq!(0, ctx.eval("g(x)+g(-x)")?.simplify(&ctx)?.to_int()?);
+    /// assert_eq!("h(x)", ctx.eval("h(x+4*pi)")?.simplify(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn assume_function(
+        &mut self,
+        name: &str,
+        property: FunctionProperty,
+    ) -> Result<(), GiacError> {
+        let name = CString::new(name).unwrap();
+        let error =
+            unsafe { ffi::giacrs_context_check_identifier(name.as_ptr(), self.as_context_ref()) };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        let (kind, period) = match property {
+            FunctionProperty::Even => (0, None),
+            FunctionProperty::Odd => (1, None),
+            FunctionProperty::Periodic(period) => {
+                (2, Some(CString::new(period.to_string()).unwrap()))
+            }
+        };
+        self.function_properties
+            .push(AssumedFunction { name, kind, period });
+        Ok(())
+    }
+
+    /// Lists the names of the variables currently assigned in the context.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.assign("a", &Gen::from(5))?;
+    /// ctx.assign("b", &Gen::from(6))?;
+    /// let vars = ctx.variables()?;
+    /// assert!(vars.contains(&"a".to_string()) && vars.contains(&"b".to_string()));
+    ///
+    /// ctx.purge("a")?;
+    /// assert!(!ctx.variables()?.contains(&"a".to_string()));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn variables(&self) -> Result<Vec<String>, GiacError> {
+        let result = Gen::new();
+        let error =
+            unsafe { ffi::giacrs_context_variables(self.as_context_ref(), result.as_gen_ref()) };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        Ok(result.to_vec()?.iter().map(|var| var.to_string()).collect())
+    }
 }
 
 impl Drop for Context {
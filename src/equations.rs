@@ -0,0 +1,160 @@
+//! Equations and systems solving
+
+use crate::{context::Context, ffi, gen::Gen, GiacError};
+
+/// Solves a system of linear equations.
+/// `equations` is a vector of equations and `vars` the vector of unknowns.
+/// Returns the vector of values of the unknowns, or an empty vector if the system is inconsistent.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::equations::linsolve;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let vars = Gen::from_str("[x,y]", &ctx)?;
+///
+/// let eqs = Gen::from_str("[x+y=3,x-y=1]", &ctx)?;
+/// assert_eq!("[2,1]", linsolve(&eqs, &vars, &ctx)?.to_string());
+///
+/// let inconsistent = Gen::from_str("[x+y=1,x+y=2]", &ctx)?;
+/// assert_eq!("[]", linsolve(&inconsistent, &vars, &ctx)?.to_string());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn linsolve(equations: &Gen, vars: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_linsolve(
+            equations.as_gen_ref(),
+            vars.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Solves the linear system `a*x = b` in matrix form, where `a` is the coefficient matrix and `b` a vector.
+/// Returns the solution vector `x`, or an empty vector if the system is inconsistent.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::equations::linsolve_matrix;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let a = Gen::from_str("[[1,1],[1,-1]]", &ctx)?;
+/// let b = Gen::from_str("[3,1]", &ctx)?;
+///
+/// assert_eq!("[2,1]", linsolve_matrix(&a, &b, &ctx)?.to_string());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn linsolve_matrix(a: &Gen, b: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_linsolve(
+            a.as_gen_ref(),
+            b.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Solves a recurrence relation for the sequence `func(var)`.
+///
+/// `recurrence` is either a single equation, or a vector whose first element is the recurrence equation
+/// and whose following elements are the initial conditions, like `[u(n+1)=2*u(n),u(0)=1]`.
+/// Without initial conditions the solution depends on arbitrary constants.
+/// Recurrences that giac can't solve either return an error or the unevaluated expression.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::equations::rsolve;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let u = Gen::from_str("u", &ctx)?;
+/// let n = Gen::from_str("n", &ctx)?;
+/// let recurrence = Gen::from_str("[u(n+1)=2*u(n),u(0)=1]", &ctx)?;
+///
+/// assert!(rsolve(&recurrence, &u, &n, &ctx)?.to_string().contains("2^n"));
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn rsolve(recurrence: &Gen, func: &Gen, var: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_rsolve(
+            recurrence.as_gen_ref(),
+            func.as_gen_ref(),
+            var.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Numerically solves a system of (possibly nonlinear) equations, starting from an initial guess.
+///
+/// `equations` is a vector of equations, `vars` the vector of unknowns and `guess` a vector of starting values,
+/// given in the same order as `vars`.
+/// Returns the vector of approximate values of the unknowns, in the order of `vars`.
+/// If the iteration doesn't converge from `guess`, a [GiacError::NoSolution] is returned;
+/// trying another starting point may help.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::equations::nsolve_system;
+/// use giacrs::gen;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let eqs = Gen::from_str("[x^2+y^2=1,x-y=0]", &ctx)?;
+/// let vars = Gen::from_str("[x,y]", &ctx)?;
+/// let guess = Gen::from_str("[0.7,0.7]", &ctx)?;
+///
+/// let solution = nsolve_system(&eqs, &vars, &guess, &ctx)?;
+/// let distance = gen!("l2norm({}-[sqrt(2)/2,sqrt(2)/2])<1e-8", solution; ctx)?;
+/// assert_eq!(1, distance.to_int()?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn nsolve_system(
+    equations: &Gen,
+    vars: &Gen,
+    guess: &Gen,
+    ctx: &Context,
+) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_nsolve_system(
+            equations.as_gen_ref(),
+            vars.as_gen_ref(),
+            guess.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error != std::ptr::null() {
+        return Err(GiacError::InternalError(error.into()));
+    }
+    if result.to_vec()?.is_empty() {
+        return Err(GiacError::NoSolution(
+            "the iteration didn't converge from the initial guess",
+        ));
+    }
+    Ok(result)
+}
@@ -20,9 +20,15 @@ extern "C" {
     ) -> GiacResult;
 
     pub fn giacrs_gen_from_int(i: std::os::raw::c_int) -> GiacGenRef;
+    pub fn giacrs_gen_from_longlong(i: std::os::raw::c_longlong) -> GiacGenRef;
+    pub fn giacrs_gen_from_ulonglong(i: std::os::raw::c_ulonglong) -> GiacGenRef;
     pub fn giacrs_gen_from_float(i: std::os::raw::c_float) -> GiacGenRef;
     pub fn giacrs_gen_from_double(i: std::os::raw::c_double) -> GiacGenRef;
     pub fn giacrs_gen_factorial(i: std::os::raw::c_ulong) -> GiacGenRef;
+    pub fn giacrs_gen_pi(ctx: GiacContextRef) -> GiacGenRef;
+    pub fn giacrs_gen_e(ctx: GiacContextRef) -> GiacGenRef;
+    pub fn giacrs_gen_i(ctx: GiacContextRef) -> GiacGenRef;
+    pub fn giacrs_gen_infinity(ctx: GiacContextRef) -> GiacGenRef;
     pub fn giacrs_free_gen(expr: GiacGenRef);
 
     pub fn giacrs_gen_clone(expr: GiacGenRef) -> GiacGenRef;
@@ -30,9 +36,33 @@ extern "C" {
     // DATA
     pub fn giacrs_gen_type(expr: GiacGenRef) -> GenType;
     pub fn giacrs_gen_is_zero(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_is_undef(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_is_infinite(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef)
+        -> GiacResult;
+    pub fn giacrs_gen_operator_name(expr: GiacGenRef) -> *const std::os::raw::c_char;
+    pub fn giacrs_gen_operands(expr: GiacGenRef, res: GiacGenRef) -> GiacResult;
+    pub fn giacrs_gen_memory_size(expr: GiacGenRef) -> usize;
+    pub fn giacrs_gen_vector_size(expr: GiacGenRef, res: *mut usize) -> GiacResult;
+    pub fn giacrs_gen_vector_at(expr: GiacGenRef, i: usize, res: GiacGenRef) -> GiacResult;
     // CONVERSION
     pub fn giacrs_gen_to_str(expr: GiacGenRef) -> *const std::os::raw::c_char;
+    pub fn giacrs_gen_print(
+        expr: GiacGenRef,
+        res: *mut *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_latex(
+        expr: GiacGenRef,
+        res: *mut *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_mathml(
+        expr: GiacGenRef,
+        res: *mut *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_to_int(expr: GiacGenRef, res: *mut std::os::raw::c_int) -> GiacResult;
+    pub fn giacrs_gen_to_double(expr: GiacGenRef, res: *mut std::os::raw::c_double) -> GiacResult;
 
     // METHODS
     pub fn giacrs_gen_gcd(
@@ -44,6 +74,12 @@ extern "C" {
     pub fn giacrs_gen_lcm(a: GiacGenRef, b: GiacGenRef, res: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_ifactor(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef)
         -> GiacResult;
+    pub fn giacrs_gen_ifactor_batch(
+        values: *const GiacGenRef,
+        res: *const GiacGenRef,
+        n: usize,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_ifactors(
         expr: GiacGenRef,
         res: GiacGenRef,
@@ -70,6 +106,7 @@ extern "C" {
     pub fn giacrs_gen_even(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef) -> GiacResult;
     pub fn giacrs_gen_odd(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef) -> GiacResult;
     pub fn giacrs_gen_is_pseudoprime(expr: GiacGenRef, res: *mut u8) -> GiacResult;
+    pub fn giacrs_gen_is_prime(expr: GiacGenRef, res: *mut bool, ctx: GiacContextRef) -> GiacResult;
     pub fn giacrs_gen_nextprime(expr: GiacGenRef, res: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_prevprime(expr: GiacGenRef, res: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_nthprime(
@@ -77,6 +114,21 @@ extern "C" {
         res: GiacGenRef,
         ctx: GiacContextRef,
     ) -> GiacResult;
+    pub fn giacrs_gen_prime_pi(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_fibonacci(
+        n: std::os::raw::c_ulong,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_lucas(
+        n: std::os::raw::c_ulong,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_iegcd(
         a: GiacGenRef,
         b: GiacGenRef,
@@ -84,6 +136,13 @@ extern "C" {
         v: GiacGenRef,
         d: GiacGenRef,
     ) -> GiacResult;
+    pub fn giacrs_gen_powmod(
+        a: GiacGenRef,
+        exp: GiacGenRef,
+        modulus: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_iabcuv(
         a: GiacGenRef,
         b: GiacGenRef,
@@ -106,6 +165,9 @@ extern "C" {
         ctx: GiacContextRef,
     ) -> GiacResult;
     pub fn giacrs_gen_euler(a: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_moebius(a: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_sigma(a: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_tau(a: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
     pub fn giacrs_gen_legendre(a: GiacGenRef, b: GiacGenRef, res: *mut i8) -> GiacResult;
     pub fn giacrs_gen_jacobi(a: GiacGenRef, b: GiacGenRef, res: *mut i8) -> GiacResult;
     pub fn giacrs_gen_comb(
@@ -121,22 +183,286 @@ extern "C" {
         ctx: GiacContextRef,
     ) -> GiacResult;
     pub fn giacrs_gen_rand(n: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_rand_range(
+        lower: GiacGenRef,
+        upper: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_random_matrix(
+        rows: usize,
+        cols: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_randbinomial(
+        n: GiacGenRef,
+        p: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_randnormal(
+        mean: GiacGenRef,
+        stddev: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_evalf(
+        expr: GiacGenRef,
+        digits: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_evalf_context(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_evalf_reliable(
+        expr: GiacGenRef,
+        target_digits: std::os::raw::c_uint,
+        max_digits: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    #[cfg(feature = "rust-decimal")]
+    pub fn giacrs_gen_decimal_mantissa(
+        expr: GiacGenRef,
+        scale: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_float2rational(
         f: GiacGenRef,
         res: GiacGenRef,
         ctx: GiacContextRef,
     ) -> GiacResult;
+    pub fn giacrs_gen_exact(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_rational_reconstruct(
+        f: GiacGenRef,
+        max_denom: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_numer(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_denom(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_to_mixed_number(
+        expr: GiacGenRef,
+        integer_part: GiacGenRef,
+        numerator: GiacGenRef,
+        denominator: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_linsolve(
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_nsolve_system(
+        equations: GiacGenRef,
+        vars: GiacGenRef,
+        guess: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_rsolve(
+        recurrence: GiacGenRef,
+        func: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_poly_compose_mod(
+        f: GiacGenRef,
+        g: GiacGenRef,
+        h: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_shift_poly_coeffs(
+        p: GiacGenRef,
+        var: GiacGenRef,
+        x0: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_proot(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_degree(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: *mut std::os::raw::c_int,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_coeff(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        n: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_coeffs(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_bernstein(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_numeric_coeffs(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_newton_polygon(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_sturm(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_count_real_roots(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: *mut std::os::raw::c_uint,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_isolate_roots(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_sqrfree(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_field_norm(
+        a: GiacGenRef,
+        minpoly: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_field_trace(
+        a: GiacGenRef,
+        minpoly: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_thiele(
+        xs: GiacGenRef,
+        ys: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_hermite_interp(
+        nodes: GiacGenRef,
+        values: GiacGenRef,
+        derivs: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_symmetric_poly(
+        k: std::os::raw::c_uint,
+        vars: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_laurent(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        at: GiacGenRef,
+        order: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_residue(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        pole: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_sum(
+        expr: GiacGenRef,
+        index: GiacGenRef,
+        lower: GiacGenRef,
+        upper: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_product(
+        expr: GiacGenRef,
+        index: GiacGenRef,
+        lower: GiacGenRef,
+        upper: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_plot_points(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        from: std::os::raw::c_double,
+        to: std::os::raw::c_double,
+        samples: usize,
+        xs: *mut std::os::raw::c_double,
+        ys: *mut std::os::raw::c_double,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_accelerate_sequence(
+        terms: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_apply(
+        func: *const std::os::raw::c_char,
+        expr: GiacGenRef,
+        args: *const GiacGenRef,
+        n: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_pow(
+        base: GiacGenRef,
+        exponent: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
 
     // OPERATOR
 
     pub fn giacrs_gen_add(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_sub(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
+    pub fn giacrs_gen_checked_add(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
+    pub fn giacrs_gen_checked_sub(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_mul(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
     pub fn giacrs_gen_div(left: GiacGenRef, right: GiacGenRef) -> GiacResult;
 }
 
 extern "C" {
     pub fn giacrs_options_set_epsilon(epsilon: std::os::raw::c_double, ctx: GiacContextRef);
+    pub fn giacrs_options_set_digits(digits: std::os::raw::c_uint, ctx: GiacContextRef);
+    pub fn giacrs_options_set_approx_mode(on: bool, ctx: GiacContextRef);
+    pub fn giacrs_options_set_complex_mode(on: bool, ctx: GiacContextRef);
+    pub fn giacrs_options_set_angle_radian(radian: bool, ctx: GiacContextRef);
+    pub fn giacrs_options_set_random_seed(seed: std::os::raw::c_ulonglong, ctx: GiacContextRef);
 }
 
 extern "C" {
@@ -146,12 +472,216 @@ extern "C" {
     pub fn giacrs_free_context(ctx: GiacContextRef);
 
     pub fn giacrs_release_globals();
+    pub fn giacrs_context_eval_with(
+        str: *const std::os::raw::c_char,
+        names: *const *const std::os::raw::c_char,
+        values: *const GiacGenRef,
+        n: usize,
+        ctx: GiacContextRef,
+        res: GiacGenRef,
+    ) -> GiacResult;
+    pub fn giacrs_context_eval_gen(
+        expr: GiacGenRef,
+        ctx: GiacContextRef,
+        res: GiacGenRef,
+    ) -> GiacResult;
+    pub fn giacrs_interrupt();
+    pub fn giacrs_is_interrupted() -> bool;
+    pub fn giacrs_clear_interrupt();
+    pub fn giacrs_context_assign(
+        name: *const std::os::raw::c_char,
+        value: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_context_get(
+        name: *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+        res: GiacGenRef,
+    ) -> GiacResult;
+    pub fn giacrs_context_purge(
+        name: *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_context_check_identifier(
+        name: *const std::os::raw::c_char,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_context_variables(ctx: GiacContextRef, res: GiacGenRef) -> GiacResult;
 
     pub fn giacrs_gen_factor(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
     pub fn giacrs_gen_simplify(
+        expr: GiacGenRef,
+        names: *const *const std::os::raw::c_char,
+        kinds: *const std::os::raw::c_int,
+        periods: *const *const std::os::raw::c_char,
+        n: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_normal(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_partial_eval(
+        expr: GiacGenRef,
+        vars: *const GiacGenRef,
+        values: *const GiacGenRef,
+        n: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_rewrite(
+        expr: GiacGenRef,
+        patterns: *const GiacGenRef,
+        replacements: *const GiacGenRef,
+        n: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_simplify_factorials(
         expr: GiacGenRef,
         res: GiacGenRef,
         ctx: GiacContextRef,
     ) -> GiacResult;
+    pub fn giacrs_gen_tlin(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_evalc(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_to_dnf(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_to_cnf(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_identity(n: usize, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_filled_matrix(
+        rows: usize,
+        cols: usize,
+        value: std::os::raw::c_int,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_matpow(
+        expr: GiacGenRef,
+        n: i64,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_lu(
+        expr: GiacGenRef,
+        p: GiacGenRef,
+        l: GiacGenRef,
+        u: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_qr(
+        expr: GiacGenRef,
+        q: GiacGenRef,
+        r: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
     pub fn giacrs_gen_det(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_cofactor(
+        expr: GiacGenRef,
+        i: usize,
+        j: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_adjoint(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_sparse_entries(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_transpose(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_dot(
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_cross(
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_norm(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_norm_p(
+        expr: GiacGenRef,
+        p: std::os::raw::c_uint,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_norm_inf(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_hconcat(
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_vconcat(
+        a: GiacGenRef,
+        b: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_submatrix(
+        expr: GiacGenRef,
+        row_start: usize,
+        row_end: usize,
+        col_start: usize,
+        col_end: usize,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_trace(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_rank(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_is_integer_matrix(
+        expr: GiacGenRef,
+        res: *mut bool,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_round_matrix_to_integer(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_rank_mod(
+        expr: GiacGenRef,
+        p: std::os::raw::c_uint,
+        res: *mut i64,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_rref(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_kernel(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_image(expr: GiacGenRef, res: GiacGenRef, ctx: GiacContextRef) -> GiacResult;
+    pub fn giacrs_gen_definiteness(
+        expr: GiacGenRef,
+        res: *mut u8,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_charpoly(
+        expr: GiacGenRef,
+        var: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_charpoly_coeffs(
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_apply_matrix_function(
+        func: *const std::os::raw::c_char,
+        expr: GiacGenRef,
+        res: GiacGenRef,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
+    pub fn giacrs_gen_dominant_eigenvalue(
+        expr: GiacGenRef,
+        iterations: std::os::raw::c_uint,
+        res: *mut std::os::raw::c_double,
+        ctx: GiacContextRef,
+    ) -> GiacResult;
 }
@@ -0,0 +1,392 @@
+//! Elementary functions
+
+use std::ffi::CString;
+
+use giacrs_internals::ffi_safe_call;
+
+use crate::{context::Context, ffi, gen::Gen, GiacError};
+
+impl Gen {
+    /// Applies the giac function named `func` to the expression and the extra arguments `args`, and evaluates `func(self, args...)`.
+    ///
+    /// This is the lower-level fallback behind the typed methods, which allows calling any giac function that doesn't have a dedicated binding yet.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(0).apply("erf", &[], &ctx)?.to_int()?);
+    /// assert_eq!("1/30", Gen::from(2).apply("Beta", &[&5.into()], &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn apply(&self, func: &str, args: &[&Gen], ctx: &Context) -> Result<Self, GiacError> {
+        let func = CString::new(func).unwrap();
+        let args: Vec<_> = args.iter().map(|arg| unsafe { arg.as_gen_ref() }).collect();
+        ffi_safe_call! {
+            ffi::giacrs_gen_apply(
+                func.as_ptr(),
+                self.as_gen_ref(),
+                args.as_ptr(),
+                args.len(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Raises the expression to the power `exponent`, like the `^` operator of giac.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let n = Gen::from_str("n", &ctx)?;
+    ///
+    /// assert_eq!(1024, Gen::from(2).pow(&Gen::from(10), &ctx)?.to_int()?);
+    /// assert_eq!("x^n", x.pow(&n, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn pow(&self, exponent: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_pow(self.as_gen_ref(), exponent.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Raises the expression to the integer power `n`, see [Gen::pow].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(1024, Gen::from(2).powi(10, &ctx)?.to_int()?);
+    /// assert_eq!("1/4", Gen::from(2).powi(-2, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn powi(&self, n: i32, ctx: &Context) -> Result<Self, GiacError> {
+        self.pow(&n.into(), ctx)
+    }
+
+    /// Computes the square root of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(2, Gen::from(4).sqrt(&ctx)?.to_int()?);
+    /// assert_eq!("sqrt(2)", Gen::from(2).sqrt(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sqrt(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("sqrt", &[], ctx)
+    }
+
+    /// Computes the exponential of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(1, Gen::from(0).exp(&ctx)?.to_int()?);
+    /// assert_eq!("exp(x)", Gen::from_str("x", &ctx)?.exp(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn exp(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("exp", &[], ctx)
+    }
+
+    /// Computes the natural logarithm of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(1).ln(&ctx)?.to_int()?);
+    /// assert_eq!("ln(x)", Gen::from_str("x", &ctx)?.ln(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn ln(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("ln", &[], ctx)
+    }
+
+    /// Computes the sine of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(0).sin(&ctx)?.to_int()?);
+    /// assert_eq!("sin(x)", Gen::from_str("x", &ctx)?.sin(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sin(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("sin", &[], ctx)
+    }
+
+    /// Computes the cosine of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(1, Gen::from(0).cos(&ctx)?.to_int()?);
+    /// assert_eq!("cos(x)", Gen::from_str("x", &ctx)?.cos(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn cos(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("cos", &[], ctx)
+    }
+
+    /// Computes the tangent of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(0).tan(&ctx)?.to_int()?);
+    /// assert_eq!("tan(x)", Gen::from_str("x", &ctx)?.tan(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn tan(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("tan", &[], ctx)
+    }
+
+    /// Computes the hyperbolic sine of the expression.
+    /// Numeric values are evaluated in approximate mode.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::GenType;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(0).sinh(&ctx)?.to_int()?);
+    /// assert_eq!("sinh(x)", Gen::from_str("x", &ctx)?.sinh(&ctx)?.to_string());
+    ///
+    /// ctx.set_approx_mode(true);
+    /// assert_eq!(GenType::Double, Gen::from(1).sinh(&ctx)?.get_type());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sinh(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("sinh", &[], ctx)
+    }
+
+    /// Computes the hyperbolic cosine of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(1, Gen::from(0).cosh(&ctx)?.to_int()?);
+    /// assert_eq!("cosh(x)", Gen::from_str("x", &ctx)?.cosh(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn cosh(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("cosh", &[], ctx)
+    }
+
+    /// Computes the hyperbolic tangent of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(0).tanh(&ctx)?.to_int()?);
+    /// assert_eq!("tanh(x)", Gen::from_str("x", &ctx)?.tanh(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn tanh(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("tanh", &[], ctx)
+    }
+
+    /// Computes the arcsine of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("pi/2", Gen::from(1).asin(&ctx)?.to_string());
+    /// assert_eq!("asin(x)", Gen::from_str("x", &ctx)?.asin(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn asin(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("asin", &[], ctx)
+    }
+
+    /// Computes the arccosine of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(1).acos(&ctx)?.to_int()?);
+    /// assert_eq!("acos(x)", Gen::from_str("x", &ctx)?.acos(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn acos(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("acos", &[], ctx)
+    }
+
+    /// Computes the arctangent of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("pi/4", Gen::from(1).atan(&ctx)?.to_string());
+    /// assert_eq!("atan(x)", Gen::from_str("x", &ctx)?.atan(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn atan(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("atan", &[], ctx)
+    }
+
+    /// Computes the angle of the point `(x, self)` from the positive x axis, in `]-pi, pi]`.
+    /// It is the argument of `x+i*self`, so unlike [Gen::atan] the quadrant is taken into account.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("pi/4", Gen::from(1).atan2(&1.into(), &ctx)?.to_string());
+    /// assert_eq!("3*pi/4", Gen::from(1).atan2(&(-1).into(), &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn atan2(&self, x: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        Gen::i(ctx).checked_mul(self)?.checked_add(x)?.arg(ctx)
+    }
+
+    /// Returns the real part of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(3, Gen::from_str("3+4*i", &ctx)?.real_part(&ctx)?.to_int()?);
+    /// assert_eq!(5, Gen::from(5).real_part(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn real_part(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("re", &[], ctx)
+    }
+
+    /// Returns the imaginary part of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(4, Gen::from_str("3+4*i", &ctx)?.imag_part(&ctx)?.to_int()?);
+    /// assert_eq!(0, Gen::from(5).imag_part(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn imag_part(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("im", &[], ctx)
+    }
+
+    /// Returns the complex conjugate of the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let z = Gen::from_str("3+4*i", &ctx)?;
+    /// assert_eq!(-4, z.conj(&ctx)?.imag_part(&ctx)?.to_int()?);
+    /// assert_eq!(25, (z.clone() * &z.conj(&ctx)?).simplify(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn conj(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("conj", &[], ctx)
+    }
+
+    /// Returns the absolute value of the expression, i.e. its modulus for a complex number.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(5, Gen::from_str("3+4*i", &ctx)?.abs(&ctx)?.to_int()?);
+    /// assert_eq!(7, Gen::from(-7).abs(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn abs(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("abs", &[], ctx)
+    }
+
+    /// Returns the argument of the expression, i.e. the angle of a complex number in `]-pi, pi]`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("pi/4", Gen::from_str("1+i", &ctx)?.arg(&ctx)?.to_string());
+    /// assert_eq!(0, Gen::from(2).arg(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn arg(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("arg", &[], ctx)
+    }
+
+    /// Restricts the expression to the interval `[low, high]`, computing `max(low, min(self, high))`.
+    ///
+    /// When the expression can't be compared to the bounds, like a variable without assumptions,
+    /// the result stays symbolic, as an unevaluated `max` of a `min`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let (low, high) = (Gen::from(0), Gen::from(5));
+    ///
+    /// assert_eq!(5, Gen::from(7).clamp(&low, &high, &ctx)?.to_int()?);
+    /// assert_eq!(0, Gen::from(-1).clamp(&low, &high, &ctx)?.to_int()?);
+    /// assert_eq!(3, Gen::from(3).clamp(&low, &high, &ctx)?.to_int()?);
+    /// let x = Gen::from_str("x", &ctx)?.clamp(&low, &high, &ctx)?;
+    /// assert_eq!("max", x.operator_name().unwrap().to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn clamp(&self, low: &Gen, high: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("min", &[high], ctx)?.apply("max", &[low], ctx)
+    }
+}
@@ -3,205 +3,1969 @@
 use std::{
     ffi::CString,
     fmt::Display,
+    hash::{Hash, Hasher},
+    iter::{Product, Sum},
     num::TryFromIntError,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Range, Sub, SubAssign},
 };
 
 use giacrs_internals::{ffi_safe_call, ffi_safe_panic_inplace_call};
 
-use crate::{context::Context, ffi, types::GenType, GiacError, GiacString};
+use crate::{
+    context::Context,
+    ffi,
+    types::{Definiteness, Field, GenType, Node, SparseEntry},
+    GiacError, GiacString,
+};
+
+pub(crate) enum GiacGen {}
+pub(crate) type GiacGenRef = *mut GiacGen;
+
+/// A giac expression.
+/// All methods which computes using an expression should return a `Result<_, GiacError>`, since giac may always throw an exception if some arguments are not correct.
+#[derive(Debug)]
+pub struct Gen(pub(crate) GiacGenRef);
+
+impl Gen {
+    /// Maximum precision, in digits, used by [Gen::evalf_reliable] before giving up
+    pub const EVALF_MAX_DIGITS: u32 = 10000;
+
+    /// Create a new empty `Gen` object
+    pub fn new() -> Self {
+        Self(unsafe { ffi::giacrs_gen_allocate() })
+    }
+
+    /// Returns the internal reference to the Cpp structure
+    pub(crate) unsafe fn as_gen_ref(&self) -> GiacGenRef {
+        self.0
+    }
+
+    /// Creates a `Gen` object from a string
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("12", &ctx)?;
+    ///
+    /// assert_eq!(12, a.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node29.html>
+    pub fn from_str(str: &str, ctx: &Context) -> Result<Self, GiacError> {
+        let s = CString::new(str).unwrap();
+        ffi_safe_call! { ffi::giacrs_gen_from_str(s.as_ptr(), ctx.as_context_ref(), result.as_gen_ref()) }
+    }
+
+    /// Creates a `Gen` object from a factorial
+    /// ```
+    /// use giacrs::gen::Gen;
+    ///
+    /// let value = Gen::factorial(24);
+    /// assert_eq!("620448401733239439360000", value.to_string());
+    /// ```
+    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node28.html>
+    pub fn factorial(i: u64) -> Self {
+        Self(unsafe { ffi::giacrs_gen_factorial(i) })
+    }
+
+    /// Creates the constant `pi`, kept symbolic until it is approximated.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("pi", Gen::pi(&ctx).to_string());
+    /// assert_eq!("3.141592654", Gen::pi(&ctx).approx(10, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn pi(ctx: &Context) -> Self {
+        Self(unsafe { ffi::giacrs_gen_pi(ctx.as_context_ref()) })
+    }
+
+    /// Creates Euler's number `e`, represented as `exp(1)`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("2.718281828", Gen::e(&ctx).approx(10, &ctx)?.to_string());
+    /// assert_eq!(1, Gen::e(&ctx).ln(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn e(ctx: &Context) -> Self {
+        Self(unsafe { ffi::giacrs_gen_e(ctx.as_context_ref()) })
+    }
+
+    /// Creates the imaginary unit `i`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let i = Gen::i(&ctx);
+    ///
+    /// assert_eq!("i", i.to_string());
+    /// assert_eq!(-1, (i.clone() * &i).to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn i(ctx: &Context) -> Self {
+        Self(unsafe { ffi::giacrs_gen_i(ctx.as_context_ref()) })
+    }
+
+    /// Creates the positive infinity `+infinity`, which can be used as a bound of limits, sums or integrals.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("+infinity", Gen::infinity(&ctx).to_string());
+    /// ```
+    pub fn infinity(ctx: &Context) -> Self {
+        Self(unsafe { ffi::giacrs_gen_infinity(ctx.as_context_ref()) })
+    }
+
+    /// Returns the string representation of an expression.
+    /// This returns a [GiacString] object.
+    /// ```
+    /// use giacrs::gen::Gen;
+    ///
+    /// assert_eq!("42", Gen::from(42).print_to_string().to_string());
+    /// assert_eq!("24", Gen::factorial(4).print_to_string().to_string());
+    /// ```
+    pub fn print_to_string(&self) -> GiacString {
+        let str = unsafe { ffi::giacrs_gen_to_str(self.as_gen_ref()) };
+        unsafe { GiacString::new(str) }
+    }
+
+    /// Like [Gen::print_to_string], with the display settings of the context, like its number of digits
+    fn print_in(&self, ctx: &Context) -> Result<GiacString, GiacError> {
+        let mut result = std::ptr::null();
+        let error =
+            unsafe { ffi::giacrs_gen_print(self.as_gen_ref(), &mut result, ctx.as_context_ref()) };
+        if error == std::ptr::null() {
+            Ok(unsafe { GiacString::new(result) })
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the LaTeX representation of an expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("\\frac{x^{2}}{2}", Gen::from_str("x^2/2", &ctx)?.to_latex(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_latex(&self, ctx: &Context) -> Result<GiacString, GiacError> {
+        let mut result = std::ptr::null();
+        let error =
+            unsafe { ffi::giacrs_gen_latex(self.as_gen_ref(), &mut result, ctx.as_context_ref()) };
+        if error == std::ptr::null() {
+            Ok(unsafe { GiacString::new(result) })
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the presentation MathML representation of an expression, as a complete `<math>...</math>` element.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let e = Gen::from_str("x^2/2", &ctx)?.to_mathml(&ctx)?.to_string();
+    /// assert!(e.starts_with("<math") && e.ends_with("</math>"));
+    /// assert!(e.contains("<msup>"));
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?.to_mathml(&ctx)?.to_string();
+    /// assert!(mat.contains("<mtable"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_mathml(&self, ctx: &Context) -> Result<GiacString, GiacError> {
+        let mut result = std::ptr::null();
+        let error =
+            unsafe { ffi::giacrs_gen_mathml(self.as_gen_ref(), &mut result, ctx.as_context_ref()) };
+        if error == std::ptr::null() {
+            Ok(unsafe { GiacString::new(result) })
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Converts an expression to a list of tokens in postfix order (Reverse Polish Notation), by walking the expression tree with [Gen::operands].
+    ///
+    /// Operator tokens are giac operator names ([Gen::operator_name]): `+`, `*`, `^`, function names like `sin`...
+    /// Giac stores `a-b` as `a+(-b)` and `a/b` as `a*inv(b)`, so the unary `-` (negation) and `inv` tokens take a single operand.
+    /// N-ary `+` and `*` are emitted as a chain of binary operators (`a+b+c` gives `a b + c +`),
+    /// other operators are emitted after all their operands. Non-symbolic leaves are printed like [Gen::print_to_string],
+    /// with the display settings of the context, so floats have its number of digits.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(vec!["x", "1", "+"], Gen::from_str("x+1", &ctx)?.to_rpn(&ctx)?);
+    /// assert_eq!(vec!["x", "2", "^", "x", "sin", "*"], Gen::from_str("x^2*sin(x)", &ctx)?.to_rpn(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_rpn(&self, ctx: &Context) -> Result<Vec<String>, GiacError> {
+        let mut tokens = vec![];
+        self.push_rpn(&mut tokens, ctx)?;
+        Ok(tokens)
+    }
+
+    fn push_rpn(&self, tokens: &mut Vec<String>, ctx: &Context) -> Result<(), GiacError> {
+        match self.operator_name() {
+            Some(op) => {
+                let op = op.to_string();
+                let operands = self.operands()?;
+                if (op == "+" || op == "*") && operands.len() >= 2 {
+                    operands[0].push_rpn(tokens, ctx)?;
+                    for operand in &operands[1..] {
+                        operand.push_rpn(tokens, ctx)?;
+                        tokens.push(op.clone());
+                    }
+                } else {
+                    for operand in &operands {
+                        operand.push_rpn(tokens, ctx)?;
+                    }
+                    tokens.push(op);
+                }
+            }
+            None => tokens.push(self.print_in(ctx)?.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Converts an expression to a primitive integer
+    /// ```
+    /// use giacrs::gen::Gen;
+    ///
+    /// assert_eq!(42, Gen::from(42).to_int()?);
+    /// assert_eq!(24, Gen::factorial(4).to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_int(&self) -> Result<i32, GiacError> {
+        let mut result = 0;
+        let error = unsafe { ffi::giacrs_gen_to_int(self.as_gen_ref(), &mut result) };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Converts a floating point expression to a primitive float
+    pub(crate) fn to_f64(&self) -> Result<f64, GiacError> {
+        let mut result = 0.;
+        let error = unsafe { ffi::giacrs_gen_to_double(self.as_gen_ref(), &mut result) };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the giac type of the expression. See [GenType]
+    /// ```
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::GenType;
+    ///
+    /// assert_eq!(GenType::Int, Gen::from(42).get_type());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn get_type(&self) -> GenType {
+        unsafe { ffi::giacrs_gen_type(self.as_gen_ref()) }
+    }
+
+    /// Returns true if the expression is an integer, either a machine integer or a big integer.
+    /// Like the following predicates, only the type of the expression is checked, see [Gen::get_type].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let one = Gen::from(1);
+    /// let big = Gen::from_str("10^30", &ctx)?;
+    /// let half = Gen::from_str("1/2", &ctx)?;
+    /// let float = Gen::from(1.5);
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let sum = Gen::from_str("x+1", &ctx)?;
+    ///
+    /// assert!(one.is_integer() && big.is_integer());
+    /// assert!(!half.is_integer() && !float.is_integer() && !x.is_integer());
+    ///
+    /// assert!(one.is_rational() && half.is_rational());
+    /// assert!(!float.is_rational() && !x.is_rational());
+    ///
+    /// assert!(one.is_real() && half.is_real() && float.is_real());
+    /// assert!(!x.is_real());
+    ///
+    /// assert!(x.is_symbolic() && sum.is_symbolic());
+    /// assert!(!one.is_symbolic() && !half.is_symbolic() && !float.is_symbolic());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_integer(&self) -> bool {
+        matches!(self.get_type(), GenType::Int | GenType::MPZInt)
+    }
+
+    /// Returns true if the expression is an exact rational number: an integer or a fraction of integers.
+    /// See [Gen::is_integer] for examples.
+    pub fn is_rational(&self) -> bool {
+        self.is_integer() || self.get_type() == GenType::Fraction
+    }
+
+    /// Returns true if the expression is a real number: a rational number or a floating point number.
+    /// Exact irrational constants like `sqrt(2)` or `pi` are symbolic, so they aren't real numbers for this predicate.
+    /// See [Gen::is_integer] for examples.
+    pub fn is_real(&self) -> bool {
+        self.is_rational()
+            || matches!(
+                self.get_type(),
+                GenType::Double | GenType::Float | GenType::MPZFloat
+            )
+    }
+
+    /// Returns true if the expression is an identifier like `x`, or a symbolic expression like `x+1`.
+    /// See [Gen::is_integer] for examples.
+    pub fn is_symbolic(&self) -> bool {
+        matches!(self.get_type(), GenType::Ident | GenType::Symbolic)
+    }
+
+    /// Returns an estimate of the memory used by the expression, in bytes.
+    ///
+    /// The expression tree is walked recursively, adding the size of each node and of the data it owns
+    /// (big integers and multiprecision floats digits, vectors, maps, strings...). Giac shares subexpressions between expressions with reference counting,
+    /// so a shared subexpression is counted each time it appears, and the memory may be freed only when no other expression uses it.
+    /// Allocator overhead and giac's global tables (like variable names) are not included,
+    /// and user defined objects are only counted as a node, since their content is opaque to giac.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let poly = Gen::from_str("x^20-1", &ctx)?.factor(&ctx)?;
+    ///
+    /// assert!(poly.memory_size() > Gen::from(1).memory_size());
+    ///
+    /// // The digits of multiprecision floats are counted
+    /// let pi = Gen::from_str("pi", &ctx)?;
+    /// assert!(pi.approx(10000, &ctx)?.memory_size() > 4000);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn memory_size(&self) -> usize {
+        unsafe { ffi::giacrs_gen_memory_size(self.as_gen_ref()) }
+    }
+
+    /// Returns the name of the top-level operator of a symbolic expression, like `+`, `*`, `^` or `sin`.
+    /// Returns `None` if the expression is not symbolic ([GenType::Symbolic]).
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let e = Gen::from_str("sin(x)+1", &ctx)?;
+    ///
+    /// assert_eq!("+", e.operator_name().unwrap().to_string());
+    /// assert!(Gen::from(2).operator_name().is_none());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn operator_name(&self) -> Option<GiacString> {
+        let str = unsafe { ffi::giacrs_gen_operator_name(self.as_gen_ref()) };
+        if str == std::ptr::null() {
+            None
+        } else {
+            Some(unsafe { GiacString::new(str) })
+        }
+    }
+
+    /// Returns the operands of the top-level operator of a symbolic expression ([GenType::Symbolic]).
+    /// Returns an empty vector if the expression is not symbolic.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let e = Gen::from_str("sin(x)+1", &ctx)?;
+    ///
+    /// let operands = e.operands()?;
+    /// assert_eq!(2, operands.len());
+    /// assert_eq!("sin(x)", operands[0].to_string());
+    /// assert_eq!(1, operands[1].to_int()?);
+    /// assert!(Gen::from(2).operands()?.is_empty());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn operands(&self) -> Result<Vec<Self>, GiacError> {
+        let result = Self::new();
+        let error = unsafe { ffi::giacrs_gen_operands(self.as_gen_ref(), result.as_gen_ref()) };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns a typed view of the top-level node of the expression, built on [Gen::operator_name] and [Gen::operands].
+    ///
+    /// Symbolic expressions ([GenType::Symbolic]) map to [Node::Add], [Node::Mul] and [Node::Pow] for the `+`, `*` and `^` operators,
+    /// and to [Node::Call] with the operator name for any other operator, like `sin`, `-` for a negation or `inv` for an inverse.
+    /// Identifiers ([GenType::Ident]) map to [Node::Symbol], and integers, floats, fractions and complex numbers to [Node::Number].
+    /// Every other expression, like a vector or a string, is returned as [Node::Other].
+    /// Symbol names are printed with the display settings of the context.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::Node;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let Node::Add(terms) = Gen::from_str("x^2+sin(y)", &ctx)?.classify(&ctx)? else { panic!() };
+    /// assert_eq!(2, terms.len());
+    /// assert!(matches!(terms[0].classify(&ctx)?, Node::Pow(..)));
+    /// assert!(matches!(terms[1].classify(&ctx)?, Node::Call(name, _) if name == "sin"));
+    /// assert!(matches!(Gen::from_str("x", &ctx)?.classify(&ctx)?, Node::Symbol(name) if name == "x"));
+    /// assert!(matches!(Gen::from_str("3/4", &ctx)?.classify(&ctx)?, Node::Number(_)));
+    /// assert!(matches!(Gen::from_str("[1,2]", &ctx)?.classify(&ctx)?, Node::Other(_)));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn classify(&self, ctx: &Context) -> Result<Node, GiacError> {
+        Ok(match self.get_type() {
+            GenType::Symbolic => {
+                let op = self.operator_name().unwrap().to_string();
+                let mut operands = self.operands()?;
+                match op.as_str() {
+                    "+" => Node::Add(operands),
+                    "*" => Node::Mul(operands),
+                    "^" if operands.len() == 2 => {
+                        let exponent = operands.pop().unwrap();
+                        let base = operands.pop().unwrap();
+                        Node::Pow(base, exponent)
+                    }
+                    _ => Node::Call(op, operands),
+                }
+            }
+            GenType::Ident => Node::Symbol(self.print_in(ctx)?.to_string()),
+            GenType::Int
+            | GenType::Double
+            | GenType::Float
+            | GenType::MPZInt
+            | GenType::MPZFloat
+            | GenType::Complex
+            | GenType::Fraction => Node::Number(self.clone()),
+            _ => Node::Other(self.clone()),
+        })
+    }
+
+    /// Checks if the expression equals zero
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert!(Gen::from(0).is_zero(&ctx)?);
+    /// assert!(!Gen::from(10).is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_zero(&self, ctx: &Context) -> Result<bool, GiacError> {
+        let mut result = false;
+        let error = unsafe {
+            ffi::giacrs_gen_is_zero(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Checks if the expression is `undef`, the value returned by giac for undefined operations like `0/0`.
+    /// `undef` propagates silently through arithmetic, so results should be checked with this method.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert!(Gen::from_str("0/0", &ctx)?.is_undef(&ctx)?);
+    /// assert!((Gen::from_str("undef", &ctx)? + &Gen::from(1)).is_undef(&ctx)?);
+    /// assert!(!Gen::from(0).is_undef(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_undef(&self, ctx: &Context) -> Result<bool, GiacError> {
+        let mut result = false;
+        let error = unsafe {
+            ffi::giacrs_gen_is_undef(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Checks if the expression is an infinity: `+infinity`, `-infinity` or the unsigned `infinity`,
+    /// returned for example by divergent limits.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert!(Gen::infinity(&ctx).is_infinite(&ctx)?);
+    /// assert!(Gen::from_str("-infinity", &ctx)?.is_infinite(&ctx)?);
+    /// assert!(!Gen::from(10).is_infinite(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_infinite(&self, ctx: &Context) -> Result<bool, GiacError> {
+        let mut result = false;
+        let error = unsafe {
+            ffi::giacrs_gen_is_infinite(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Splits a vector expression into its elements
+    pub(crate) fn to_vec(&self) -> Result<Vec<Self>, GiacError> {
+        let mut size = 0;
+        let error = unsafe { ffi::giacrs_gen_vector_size(self.as_gen_ref(), &mut size) };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        (0..size)
+            .map(|i| {
+                ffi_safe_call! { ffi::giacrs_gen_vector_at(self.as_gen_ref(), i, result.as_gen_ref()) }
+            })
+            .collect()
+    }
+
+    /// Factorizes the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let poly = Gen::from_str("x^2-1", &ctx)?;
+    ///
+    /// assert_eq!("(x-1)*(x+1)", poly.factor(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn factor(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_factor(self.as_gen_ref(),result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Simplifies the expression.
+    /// The properties of functions assumed with [Context::assume_function] are used.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let poly = Gen::from_str("(x-1)*(x+1)", &ctx)?;
+    ///
+    /// assert_eq!("x^2-1", poly.simplify(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn simplify(&self, ctx: &Context) -> Result<Self, GiacError> {
+        let properties = ctx.function_properties();
+        let names: Vec<_> = properties.iter().map(|p| p.name.as_ptr()).collect();
+        let kinds: Vec<_> = properties.iter().map(|p| p.kind).collect();
+        let periods: Vec<_> = properties
+            .iter()
+            .map(|p| {
+                p.period
+                    .as_ref()
+                    .map_or(std::ptr::null(), |period| period.as_ptr())
+            })
+            .collect();
+        ffi_safe_call! {
+            ffi::giacrs_gen_simplify(
+                self.as_gen_ref(),
+                names.as_ptr(),
+                kinds.as_ptr(),
+                periods.as_ptr(),
+                properties.len(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Reduces a fraction to lowest terms, dividing the numerator and the denominator by their gcd.
+    /// Rational functions are reduced with giac's `normal`, which cancels common polynomial factors.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let a = Gen::from(4) / &Gen::from(8);
+    /// assert_eq!("1/2", a.reduce_fraction(&ctx)?.to_string());
+    /// let b = Gen::from_str("x^2-1", &ctx)? / &Gen::from_str("x-1", &ctx)?;
+    /// assert_eq!("x+1", b.reduce_fraction(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn reduce_fraction(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_normal(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Substitutes each variable of `subs` by its value, in parallel, and normalizes the result, in a single call to giac.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let e = Gen::from_str("x^2+y", &ctx)?;
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let y = Gen::from_str("y", &ctx)?;
+    ///
+    /// assert_eq!("y+4", e.partial_eval(&[(x.clone(), 2.into())], &ctx)?.to_string());
+    /// // Substitutions are done in parallel
+    /// let swapped = e.partial_eval(&[(x.clone(), y.clone()), (y, x)], &ctx)?;
+    /// let expected = Gen::from_str("y^2+x", &ctx)?;
+    /// assert!((swapped - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn partial_eval(&self, subs: &[(Gen, Gen)], ctx: &Context) -> Result<Self, GiacError> {
+        let vars: Vec<GiacGenRef> = subs
+            .iter()
+            .map(|(var, _)| unsafe { var.as_gen_ref() })
+            .collect();
+        let values: Vec<GiacGenRef> = subs
+            .iter()
+            .map(|(_, value)| unsafe { value.as_gen_ref() })
+            .collect();
+        ffi_safe_call! {
+            ffi::giacrs_gen_partial_eval(
+                self.as_gen_ref(),
+                vars.as_ptr(),
+                values.as_ptr(),
+                subs.len(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Applies rewrite rules `pattern -> replacement` to the expression, then evaluates the result.
+    ///
+    /// Identifiers ending with `_`, like `x_`, are pattern variables: they match any subexpression, which is then
+    /// substituted for them in the replacement. A variable appearing several times must match equal subexpressions.
+    /// Other parts of the pattern must match exactly, as stored by giac: the pattern is evaluated when parsed,
+    /// and operands of sums and products are matched in order, so a pattern matches a whole sum, not some of its terms.
+    ///
+    /// The expression is walked from the root, and at each subexpression the first matching rule is applied.
+    /// A replaced subexpression isn't walked again, so rules can't loop.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let rule = (Gen::from_str("sin(x_)^2", &ctx)?, Gen::from_str("1-cos(x_)^2", &ctx)?);
+    ///
+    /// let e = Gen::from_str("sin(x)^2+cos(x)^2", &ctx)?;
+    /// assert_eq!(1, e.rewrite_rules(&[rule.clone()], &ctx)?.simplify(&ctx)?.to_int()?);
+    /// let f = Gen::from_str("2*sin(y+1)^2*y", &ctx)?.rewrite_rules(&[rule], &ctx)?;
+    /// let expected = Gen::from_str("2*(1-cos(y+1)^2)*y", &ctx)?;
+    /// assert!((f - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    ///
+    /// // A repeated variable only matches equal arguments
+    /// let rule = (Gen::from_str("f(x_,x_)", &ctx)?, Gen::from_str("g(x_)", &ctx)?);
+    /// let g = Gen::from_str("f(a,a)+f(a,b)", &ctx)?.rewrite_rules(&[rule], &ctx)?;
+    /// assert_eq!(0, (g - &Gen::from_str("g(a)+f(a,b)", &ctx)?).simplify(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn rewrite_rules(&self, rules: &[(Gen, Gen)], ctx: &Context) -> Result<Self, GiacError> {
+        let patterns: Vec<GiacGenRef> = rules
+            .iter()
+            .map(|(pattern, _)| unsafe { pattern.as_gen_ref() })
+            .collect();
+        let replacements: Vec<GiacGenRef> = rules
+            .iter()
+            .map(|(_, replacement)| unsafe { replacement.as_gen_ref() })
+            .collect();
+        ffi_safe_call! {
+            ffi::giacrs_gen_rewrite(
+                self.as_gen_ref(),
+                patterns.as_ptr(),
+                replacements.as_ptr(),
+                rules.len(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Simplifies ratios of factorials, like `(n+1)!/n!`.
+    ///
+    /// Factorials of shifted arguments are expanded (`(n+1)! = (n+1)*n!`) before normalizing, which is only valid for integers:
+    /// the variables should be assumed integers in the context, with `assume(n,integer)`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// ctx.eval("assume(n,integer)")?;
+    ///
+    /// let a = Gen::from_str("(n+1)!/n!", &ctx)?;
+    /// assert_eq!("n+1", a.simplify_factorials(&ctx)?.to_string());
+    ///
+    /// // comb(n+2,2)/comb(n+1,2)
+    /// let b = Gen::from_str("((n+2)!/(2*n!))/((n+1)!/(2*(n-1)!))", &ctx)?;
+    /// let expected = Gen::from_str("(n+2)/n", &ctx)?;
+    /// assert!((b.simplify_factorials(&ctx)? - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn simplify_factorials(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_simplify_factorials(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Linearizes products of trigonometric functions into sums, like `sin(x)*cos(x) = sin(2*x)/2`.
+    ///
+    /// This is giac's `tlin`, which also linearizes integer powers, as a power is a product of equal factors:
+    /// `sin(x)^2` becomes `1/2-cos(2*x)/2`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let a = Gen::from_str("sin(x)*cos(x)", &ctx)?;
+    /// let expected = Gen::from_str("sin(2*x)/2", &ctx)?;
+    /// assert!((a.trig_product_to_sum(&ctx)? - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    ///
+    /// let b = Gen::from_str("sin(x)*cos(x)*cos(2*x)", &ctx)?;
+    /// let expected = Gen::from_str("sin(4*x)/4", &ctx)?;
+    /// assert!((b.trig_product_to_sum(&ctx)? - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn trig_product_to_sum(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_tlin(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Writes a complex expression in the form `a+b*i`, collecting its real and imaginary parts (giac's `evalc`).
+    ///
+    /// Variables are assumed to be real, so a symbolic expression is split as if its variables had no imaginary part,
+    /// like `(x+i*y)^2 = x^2-y^2+2*x*y*i`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let a = Gen::from_str("3+4*i", &ctx)? + &Gen::from_str("1-2*i", &ctx)?;
+    /// assert_eq!("4+2*i", a.simplify_complex(&ctx)?.to_string());
+    /// let b = Gen::from_str("(1+2*i)*(3-i)", &ctx)?;
+    /// assert_eq!("5+5*i", b.simplify_complex(&ctx)?.to_string());
+    ///
+    /// let c = Gen::from_str("(x+i*y)^2", &ctx)?.simplify_complex(&ctx)?;
+    /// let expected = Gen::from_str("x^2-y^2", &ctx)?;
+    /// assert!((c.real_part(&ctx)? - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn simplify_complex(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_evalc(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Converts a propositional formula to disjunctive normal form, an `or` of `and` clauses of literals.
+    ///
+    /// The supported connectives are `and`, `or`, `not` and `xor`, the variables must be identifiers,
+    /// and the constants `true` and `false` are simplified away. An error is returned for any other expression.
+    /// Negations are first pushed to the variables with De Morgan's laws, then `and` is distributed over `or`
+    /// with giac's `expand`, which can make the formula exponentially larger.
+    /// Repeated literals and clauses are merged, but contradictory clauses like `a and not(a)` are not removed.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::Node;
+    ///
+    /// let ctx = Context::new();
+    /// let e = Gen::from_str("(a or b) and c", &ctx)?;
+    ///
+    /// let Node::Call(op, clauses) = e.to_dnf(&ctx)?.classify(&ctx)? else { panic!() };
+    /// assert_eq!("or", op);
+    /// assert_eq!(2, clauses.len());
+    /// assert!(matches!(clauses[0].classify(&ctx)?, Node::Call(op, _) if op == "and"));
+    /// assert!(Gen::from_str("a+1 and b", &ctx)?.to_dnf(&ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_dnf(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_to_dnf(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Converts a propositional formula to conjunctive normal form, an `and` of `or` clauses of literals.
+    ///
+    /// Like [Gen::to_dnf], the supported connectives are `and`, `or`, `not` and `xor`, and the variables must be identifiers.
+    /// `or` is distributed over `and` with giac's `expand`, which can make the formula exponentially larger.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::Node;
+    ///
+    /// let ctx = Context::new();
+    /// // (a or c) and (b or c)
+    /// let e = Gen::from_str("(a and b) or c", &ctx)?;
+    ///
+    /// let Node::Call(op, clauses) = e.to_cnf(&ctx)?.classify(&ctx)? else { panic!() };
+    /// assert_eq!("and", op);
+    /// assert_eq!(2, clauses.len());
+    /// assert!(matches!(clauses[1].classify(&ctx)?, Node::Call(op, _) if op == "or"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_cnf(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_to_cnf(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Creates the `n`x`n` identity matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("[[1,0,0],[0,1,0],[0,0,1]]", Gen::identity(3, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn identity(n: usize, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_identity(n, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Creates a `rows`x`cols` matrix filled with zeros.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("[[0,0,0],[0,0,0]]", Gen::zeros(2, 3, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn zeros(rows: usize, cols: usize, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_filled_matrix(rows, cols, 0, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Creates a `rows`x`cols` matrix filled with ones.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("[[1,1],[1,1],[1,1]]", Gen::ones(3, 2, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn ones(rows: usize, cols: usize, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_filled_matrix(rows, cols, 1, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Raises a square matrix to the power `n`, as a matrix product (not element-wise).
+    /// A negative `n` raises the inverse matrix to the power `-n`, an error is returned if the matrix isn't invertible.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,1],[0,1]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1,5],[0,1]]", mat.matpow(5, &ctx)?.to_string());
+    /// assert_eq!("[[1,-3],[0,1]]", mat.matpow(-3, &ctx)?.to_string());
+    /// assert_eq!("[[1,0],[0,1]]", mat.matpow(0, &ctx)?.to_string());
+    /// assert!(Gen::from_str("[[1,2],[2,4]]", &ctx)?.matpow(-1, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn matpow(&self, n: i64, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_matpow(self.as_gen_ref(), n, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the LU decomposition of a square matrix `A`, returning `(P, L, U)` such that `P*A = L*U`,
+    /// where `P` is a permutation matrix, `L` is lower triangular with ones on its diagonal and `U` is upper triangular.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// let (p, l, u) = a.lu(&ctx)?;
+    /// let difference = p * &a - &(l * &u);
+    /// assert_eq!("[[0,0],[0,0]]", difference.simplify(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn lu(&self, ctx: &Context) -> Result<(Self, Self, Self), GiacError> {
+        let p = Self::new();
+        let l = Self::new();
+        let u = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_lu(
+                self.as_gen_ref(),
+                p.as_gen_ref(),
+                l.as_gen_ref(),
+                u.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok((p, l, u))
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Computes the QR decomposition of a matrix `A`, returning `(Q, R)` such that `A = Q*R`,
+    /// where `Q` is orthogonal and `R` is upper triangular.
+    /// For a numeric matrix, the product reconstructs `A` up to rounding errors.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1.0,2.0],[3.0,4.0]]", &ctx)?;
+    ///
+    /// let (q, r) = a.qr(&ctx)?;
+    /// let error = (q * &r - &a).norm(&ctx)?;
+    /// assert_eq!(1, gen!("{}<1e-10", error; ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn qr(&self, ctx: &Context) -> Result<(Self, Self), GiacError> {
+        let q = Self::new();
+        let r = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_qr(
+                self.as_gen_ref(),
+                q.as_gen_ref(),
+                r.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok((q, r))
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Computes the determinant of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// assert_eq!(-2, mat.det(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+
+    pub fn det(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_det(self.as_gen_ref(),result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the cofactor of the entry at row `i` and column `j` of a square matrix (indices start at `0`),
+    /// i.e. `(-1)^(i+j)` times the determinant of the matrix without row `i` and column `j`.
+    /// It's the entry of giac's `comatrix`, the matrix of cofactors.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// assert_eq!(4, mat.cofactor(0, 0, &ctx)?.to_int()?);
+    /// assert_eq!(-3, mat.cofactor(0, 1, &ctx)?.to_int()?);
+    /// assert!(mat.cofactor(2, 0, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn cofactor(&self, i: usize, j: usize, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_cofactor(self.as_gen_ref(), i, j, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the adjugate of a square matrix, i.e. the transpose of its cofactor matrix (giac's `comatrix`, see [Gen::cofactor]).
+    /// It satisfies `adjoint(A)*A = det(A)*I`, so `adjoint(A) = det(A)*inv(A)` when `A` is invertible.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    /// assert_eq!("[[4,-2],[-3,1]]", a.adjoint(&ctx)?.to_string());
+    ///
+    /// let b = Gen::from_str("[[2,1,0],[1,3,1],[0,1,4]]", &ctx)?;
+    /// let expected = b.det(&ctx)? * &b.apply("inv", &[], &ctx)?;
+    /// let difference = (b.adjoint(&ctx)? - &expected).simplify(&ctx)?;
+    /// assert_eq!("[[0,0,0],[0,0,0],[0,0,0]]", difference.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn adjoint(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_adjoint(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the non-zero entries of a matrix as `((row, column), value)` tuples, indices starting at `0`.
+    /// Entries are ordered row by row, then by column.
+    ///
+    /// An error is returned if the expression isn't a matrix (a vector of rows of the same length).
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[0,0,3],[0,0,0],[x,0,0]]", &ctx)?;
+    ///
+    /// let entries = mat.to_sparse(&ctx)?;
+    /// assert_eq!(2, entries.len());
+    /// assert_eq!((0, 2), entries[0].0);
+    /// assert_eq!(3, entries[0].1.to_int()?);
+    /// assert_eq!((2, 0), entries[1].0);
+    /// assert_eq!("x", entries[1].1.to_string());
+    /// assert!(Gen::from(1).to_sparse(&ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_sparse(&self, ctx: &Context) -> Result<Vec<SparseEntry>, GiacError> {
+        let result = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_sparse_entries(
+                self.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        result
+            .to_vec()?
+            .into_iter()
+            .map(|entry| {
+                let entry = entry.to_vec()?;
+                let (i, j) = (entry[0].to_int()? as usize, entry[1].to_int()? as usize);
+                Ok(((i, j), entry[2].clone()))
+            })
+            .collect()
+    }
+
+    /// Transposes the matrix.
+    /// A row vector is turned into a column matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    /// let row = Gen::from_str("[[1,2,3]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1,3],[2,4]]", mat.transpose(&ctx)?.to_string());
+    /// assert_eq!("[[1],[2],[3]]", row.transpose(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn transpose(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_transpose(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Concatenates two matrices horizontally, placing the columns of `other` after the columns of `self`.
+    /// An error is returned if the matrices don't have the same number of rows.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1],[2]]", &ctx)?;
+    /// let b = Gen::from_str("[[3],[4]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1,3],[2,4]]", a.hconcat(&b, &ctx)?.to_string());
+    /// assert!(a.hconcat(&Gen::from_str("[[1,2]]", &ctx)?, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn hconcat(&self, other: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_hconcat(self.as_gen_ref(), other.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Concatenates two matrices vertically, placing the rows of `other` below the rows of `self`.
+    /// An error is returned if the matrices don't have the same number of columns.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1],[2]]", &ctx)?;
+    /// let b = Gen::from_str("[[3],[4]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1],[2],[3],[4]]", a.vconcat(&b, &ctx)?.to_string());
+    /// assert!(a.vconcat(&Gen::from_str("[[1,2]]", &ctx)?, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn vconcat(&self, other: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_vconcat(self.as_gen_ref(), other.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Extracts the block of the matrix made of the rows in `rows` and the columns in `cols`, indices starting at 0.
+    /// An error is returned if a range is empty or goes out of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2,3],[4,5,6]]", &ctx)?;
+    ///
+    /// assert_eq!("[[2,3]]", mat.submatrix(0..1, 1..3, &ctx)?.to_string());
+    /// assert_eq!("[[1],[4]]", mat.submatrix(0..2, 0..1, &ctx)?.to_string());
+    /// assert!(mat.submatrix(0..3, 0..1, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        ctx: &Context,
+    ) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_submatrix(
+                self.as_gen_ref(),
+                rows.start,
+                rows.end,
+                cols.start,
+                cols.end,
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Computes the dot product of two vectors.
+    /// An error is returned if the expressions aren't vectors of the same length.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let u = Gen::from_str("[1,2,3]", &ctx)?;
+    /// let v = Gen::from_str("[4,5,6]", &ctx)?;
+    ///
+    /// assert_eq!(32, u.dot(&v, &ctx)?.to_int()?);
+    /// assert!(u.dot(&Gen::from_str("[1,2]", &ctx)?, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn dot(&self, other: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_dot(self.as_gen_ref(), other.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the cross product of two vectors of dimension 3, which is orthogonal to both of them.
+    /// An error is returned if the expressions aren't vectors with 3 coordinates.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let u = Gen::from_str("[1,0,0]", &ctx)?;
+    /// let v = Gen::from_str("[0,1,0]", &ctx)?;
+    ///
+    /// assert_eq!("[0,0,1]", u.cross(&v, &ctx)?.to_string());
+    /// assert!(Gen::from_str("[1,2]", &ctx)?.cross(&Gen::from_str("[3,4]", &ctx)?, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn cross(&self, other: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_cross(self.as_gen_ref(), other.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the Euclidean norm of a vector (giac's `l2norm`), or the Frobenius norm of a matrix (giac's `frobenius_norm`),
+    /// the square root of the sum of the squared absolute values of its entries.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(5, Gen::from_str("[3,4]", &ctx)?.norm(&ctx)?.to_int()?);
+    /// assert_eq!("sqrt(30)", Gen::from_str("[[1,2],[3,4]]", &ctx)?.norm(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn norm(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_norm(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the `p`-norm of a vector, `(sum(abs(x_i)^p))^(1/p)`, which is giac's `l1norm` for `p = 1`
+    /// and `l2norm` for `p = 2`.
+    ///
+    /// For a matrix, only `p = 1` is supported, giving the induced 1-norm: the maximum absolute column sum (giac's `colnorm`).
+    /// An error is returned if `p` is zero, see [Gen::norm_inf] for the infinity norm.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let v = Gen::from_str("[3,-4]", &ctx)?;
+    ///
+    /// assert_eq!(7, v.norm_p(1, &ctx)?.to_int()?);
+    /// assert_eq!(5, v.norm_p(2, &ctx)?.to_int()?);
+    /// assert_eq!("91^(1/3)", v.norm_p(3, &ctx)?.to_string());
+    /// assert_eq!(6, Gen::from_str("[[1,2],[3,-4]]", &ctx)?.norm_p(1, &ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn norm_p(&self, p: u32, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_norm_p(self.as_gen_ref(), p, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the infinity norm of a vector, the maximum absolute value of its entries (giac's `maxnorm`),
+    /// or the induced infinity norm of a matrix, the maximum absolute row sum (giac's `rownorm`).
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(4, Gen::from_str("[3,-4]", &ctx)?.norm_inf(&ctx)?.to_int()?);
+    /// assert_eq!(7, Gen::from_str("[[1,2],[3,-4]]", &ctx)?.norm_inf(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn norm_inf(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_norm_inf(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the trace of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// assert_eq!(5, mat.trace(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn trace(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_trace(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the rank of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    /// let singular = Gen::from_str("[[1,2],[2,4]]", &ctx)?;
+    ///
+    /// assert_eq!(2, mat.rank(&ctx)?.to_int()?);
+    /// assert_eq!(1, singular.rank(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn rank(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_rank(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns true if every entry of the matrix is an integer, up to the context epsilon (see [Context::set_epsilon]).
+    /// Entries are evaluated numerically and compared to their rounded value,
+    /// so float entries like `2.0` are accepted. Returns false if the expression isn't a matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert!(Gen::from_str("[[1,2],[3,4]]", &ctx)?.is_integer_matrix(&ctx)?);
+    /// assert!(Gen::from_str("[[1.0,2.0],[3.0,4.0]]", &ctx)?.is_integer_matrix(&ctx)?);
+    /// assert!(!Gen::from_str("[[1.5,2],[3,4]]", &ctx)?.is_integer_matrix(&ctx)?);
+    /// assert!(!Gen::from(1).is_integer_matrix(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_integer_matrix(&self, ctx: &Context) -> Result<bool, GiacError> {
+        let mut result = false;
+        let error = unsafe {
+            ffi::giacrs_gen_is_integer_matrix(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Rounds every entry of the matrix to the nearest integer.
+    /// An error is returned if the expression isn't a matrix, or if an entry is farther than the context epsilon
+    /// (see [Context::set_epsilon]) from an integer, like [Gen::is_integer_matrix].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1.0,2.0],[3.0,4.0]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1,2],[3,4]]", mat.round_matrix_to_integer(&ctx)?.to_string());
+    /// assert!(Gen::from_str("[[1.5,2],[3,4]]", &ctx)?.round_matrix_to_integer(&ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn round_matrix_to_integer(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_round_matrix_to_integer(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes the rank of the matrix over the given [Field].
+    ///
+    /// Over [Field::Rational] this is the exact rank computed by [Gen::rank].
+    /// Over [Field::Modular] the entries are first mapped to `ℤ/pℤ` (as giac modular numbers, like `a % p`),
+    /// so the rank may be lower than over the rationals. An error is returned if `p` isn't prime or exceeds `i32::MAX`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::Field;
+    ///
+    /// let ctx = Context::new();
+    /// // The determinant is -2, which vanishes modulo 2
+    /// let mat = Gen::from_str("[[1,1],[1,-1]]", &ctx)?;
+    ///
+    /// assert_eq!(2, mat.rank_over(Field::Rational, &ctx)?);
+    /// assert_eq!(1, mat.rank_over(Field::Modular(2), &ctx)?);
+    /// assert_eq!(2, mat.rank_over(Field::Modular(3), &ctx)?);
+    /// assert!(mat.rank_over(Field::Modular(4), &ctx).is_err());
+    /// assert!(mat.rank_over(Field::Modular(u32::MAX), &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn rank_over(&self, field: Field, ctx: &Context) -> Result<i64, GiacError> {
+        match field {
+            Field::Rational => Ok(self.rank(ctx)?.to_int()?.into()),
+            Field::Modular(p) => {
+                let mut result = 0;
+                let error = unsafe {
+                    ffi::giacrs_gen_rank_mod(
+                        self.as_gen_ref(),
+                        p,
+                        &mut result,
+                        ctx.as_context_ref(),
+                    )
+                };
+                if error == std::ptr::null() {
+                    Ok(result)
+                } else {
+                    Err(GiacError::InternalError(error.into()))
+                }
+            }
+        }
+    }
+
+    /// Computes the reduced row echelon form of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2,3],[2,4,7]]", &ctx)?;
+    ///
+    /// assert_eq!("[[1,2,0],[0,0,1]]", mat.rref(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn rref(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_rref(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Computes a basis of the kernel (null space) of the matrix, i.e. of the vectors `v` such that `A*v = 0`.
+    /// Returns an empty vector if the kernel is trivial.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[2,4]]", &ctx)?;
+    ///
+    /// let kernel = mat.kernel(&ctx)?;
+    /// assert_eq!(1, kernel.len());
+    /// assert_eq!("[0,0]", (mat * &kernel[0]).simplify(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn kernel(&self, ctx: &Context) -> Result<Vec<Self>, GiacError> {
+        let result = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_kernel(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Computes a basis of the image (column space) of the matrix.
+    /// Its length is the [rank](Gen::rank) of the matrix.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(1, Gen::from_str("[[1,2],[2,4]]", &ctx)?.image(&ctx)?.len());
+    /// assert_eq!(2, Gen::from_str("[[1,2],[3,4]]", &ctx)?.image(&ctx)?.len());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn image(&self, ctx: &Context) -> Result<Vec<Self>, GiacError> {
+        let result = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_image(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Classifies a symmetric matrix (a quadratic form) by the signs of its eigenvalues. See [Definiteness].
+    ///
+    /// The matrix must be symmetric, otherwise an error is returned.
+    /// If the sign of an eigenvalue can't be determined (e.g. it depends on a symbolic parameter), an error is returned too.
+    /// The zero matrix is reported as [Definiteness::PositiveSemidefinite].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::Definiteness;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[2,-1],[-1,2]]", &ctx)?;
+    /// let b = Gen::from_str("[[1,1],[1,1]]", &ctx)?;
+    /// let c = Gen::from_str("[[1,2],[2,1]]", &ctx)?;
+    /// let d = Gen::from_str("[[a,0],[0,1]]", &ctx)?;
+    ///
+    /// assert_eq!(Definiteness::PositiveDefinite, a.definiteness(&ctx)?);
+    /// assert_eq!(Definiteness::PositiveSemidefinite, b.definiteness(&ctx)?);
+    /// assert_eq!(Definiteness::Indefinite, c.definiteness(&ctx)?);
+    /// assert!(d.definiteness(&ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn definiteness(&self, ctx: &Context) -> Result<Definiteness, GiacError> {
+        let mut result: u8 = 0;
+        let error = unsafe {
+            ffi::giacrs_gen_definiteness(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(match result {
+                0 => Definiteness::PositiveDefinite,
+                1 => Definiteness::PositiveSemidefinite,
+                2 => Definiteness::NegativeDefinite,
+                3 => Definiteness::NegativeSemidefinite,
+                4 => Definiteness::Indefinite,
+                _ => unreachable!(),
+            })
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Computes the characteristic polynomial of the matrix in the variable `var`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// assert_eq!("x^2-5*x-2", mat.charpoly(&x, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn charpoly(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_charpoly(self.as_gen_ref(), var.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Computes the coefficients of the characteristic polynomial of the matrix, from the highest to the lowest degree.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// let coeffs = mat.charpoly_coeffs(&ctx)?;
+    /// let coeffs: Vec<i32> = coeffs.iter().map(|c| c.to_int()).collect::<Result<_, _>>()?;
+    /// assert_eq!(vec![1, -5, -2], coeffs);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn charpoly_coeffs(&self, ctx: &Context) -> Result<Vec<Self>, GiacError> {
+        let result = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_charpoly_coeffs(
+                self.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Applies the scalar function named `func` to a square matrix, i.e. computes `f(A) = P*f(D)*P^-1`
+    /// where `A = P*D*P^-1` with `D` diagonal, `f` being applied to each eigenvalue of the diagonal.
+    ///
+    /// The matrix must be diagonalizable, an error is returned otherwise.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// let a = Gen::from_str("[[0,0],[0,pi/2]]", &ctx)?;
+    /// assert_eq!("[[0,0],[0,1]]", a.apply_matrix_function("sin", &ctx)?.to_string());
+    ///
+    /// let b = Gen::from_str("[[2,1],[1,2]]", &ctx)?;
+    /// let sqrt_b = b.apply_matrix_function("sqrt", &ctx)?;
+    /// let difference = sqrt_b.clone() * &sqrt_b - &b;
+    /// assert_eq!("[[0,0],[0,0]]", difference.simplify(&ctx)?.to_string());
+    ///
+    /// // Not diagonalizable
+    /// let c = Gen::from_str("[[1,1],[0,1]]", &ctx)?;
+    /// assert!(c.apply_matrix_function("sin", &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn apply_matrix_function(&self, func: &str, ctx: &Context) -> Result<Self, GiacError> {
+        let func = CString::new(func).unwrap();
+        ffi_safe_call! {
+            ffi::giacrs_gen_apply_matrix_function(func.as_ptr(), self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Estimates the dominant eigenvalue (the one with the largest absolute value) of a numeric square matrix
+    /// with `iterations` steps of the power method, computed with floating point numbers.
+    ///
+    /// This is much cheaper than computing all the eigenvalues, but it only converges if the dominant eigenvalue is real
+    /// and strictly larger in absolute value than the other ones; the convergence is slow if they are close.
+    /// The starting vector must also not be orthogonal to the dominant eigenvector, which is unlikely in practice.
+    /// An error is returned if `iterations` is zero, if the expression isn't a non-empty square matrix or if an entry isn't a real number.
+    /// If the iterates vanish, like for a zero or a nilpotent matrix, all the eigenvalues are zero and `0.0` is returned.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// // Eigenvalues are (5+sqrt(5))/2 and (5-sqrt(5))/2, the starting vector isn't an eigenvector
+    /// let mat = Gen::from_str("[[2,1],[1,3]]", &ctx)?;
+    /// let expected = (5.0 + 5f64.sqrt()) / 2.0;
+    ///
+    /// assert!((mat.dominant_eigenvalue(100, &ctx)? - expected).abs() < 1e-8);
+    /// assert!(Gen::from_str("[[x,1],[2,3]]", &ctx)?.dominant_eigenvalue(100, &ctx).is_err());
+    /// assert!(mat.dominant_eigenvalue(0, &ctx).is_err());
+    /// assert_eq!(0.0, Gen::from_str("[[0,1],[0,0]]", &ctx)?.dominant_eigenvalue(10, &ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn dominant_eigenvalue(&self, iterations: u32, ctx: &Context) -> Result<f64, GiacError> {
+        let mut result = 0.0;
+        let error = unsafe {
+            ffi::giacrs_gen_dominant_eigenvalue(
+                self.as_gen_ref(),
+                iterations,
+                &mut result,
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
 
-pub(crate) enum GiacGen {}
-pub(crate) type GiacGenRef = *mut GiacGen;
+    // GIAC METHODS
 
-/// A giac expression.
-/// All methods which computes using an expression should return a `Result<_, GiacError>`, since giac may always throw an exception if some arguments are not correct.
-#[derive(Debug)]
-pub struct Gen(pub(crate) GiacGenRef);
+    /// Computes a numeric approximation of the expression with `digits` significant digits,
+    /// see [Gen::evalf] to use the digits of the context instead.
+    /// This is the inverse of [Gen::float_to_rational].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let pi = Gen::from_str("pi", &ctx)?;
+    ///
+    /// assert_eq!("3.141592654", pi.approx(10, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn approx(&self, digits: u32, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_evalf(self.as_gen_ref(), digits, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
 
-impl Gen {
-    /// Create a new empty `Gen` object
-    pub fn new() -> Self {
-        Self(unsafe { ffi::giacrs_gen_allocate() })
+    /// Computes a numeric approximation of the expression with the number of digits of the context,
+    /// set with [Context::set_digits].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    /// let pi = Gen::from_str("pi", &ctx)?;
+    ///
+    /// ctx.set_digits(20);
+    /// assert!(pi.evalf(&ctx)?.to_string().starts_with("3.14159265358979323"));
+    /// ctx.set_digits(5);
+    /// assert!(!pi.evalf(&ctx)?.to_string().starts_with("3.14159265"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn evalf(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_evalf_context(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
-    /// Returns the internal reference to the Cpp structure
-    pub(crate) unsafe fn as_gen_ref(&self) -> GiacGenRef {
-        self.0
+    /// Computes a numeric approximation of the expression with `target_digits` correct significant digits,
+    /// unlike [Gen::approx] which can return noise when catastrophic cancellation occurs.
+    ///
+    /// The expression is first evaluated with `target_digits + 10` digits, then the precision is doubled
+    /// until two successive values agree to `target_digits` digits.
+    /// An error is returned if the expression isn't numeric, if `target_digits` isn't lower than [Gen::EVALF_MAX_DIGITS],
+    /// or if the values are still unstable when the precision would exceed [Gen::EVALF_MAX_DIGITS].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// // Almost an integer, the difference cancels the 30 first digits
+    /// let e = Gen::from_str("exp(pi*sqrt(163))-640320^3-744", &ctx)?;
+    ///
+    /// assert!(e.evalf_reliable(10, &ctx)?.to_string().starts_with("-7.49927402"));
+    /// assert!(Gen::from_str("x+1", &ctx)?.evalf_reliable(10, &ctx).is_err());
+    /// assert!(e.evalf_reliable(u32::MAX, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn evalf_reliable(&self, target_digits: u32, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_evalf_reliable(
+                self.as_gen_ref(),
+                target_digits,
+                Self::EVALF_MAX_DIGITS,
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
     }
 
-    /// Creates a `Gen` object from a string
+    /// Converts the expression to a [Decimal](rust_decimal::Decimal) with `scale` digits after the decimal point,
+    /// rounding to the nearest value. The rounding is done by giac on the exact value, so no floating point error is introduced.
+    ///
+    /// An error is returned if the expression isn't a real number, if `scale` exceeds 28,
+    /// or if the value exceeds the range of a `Decimal` (a mantissa of 96 bits, i.e. about `7.9e28 / 10^scale`).
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
+    /// use rust_decimal::Decimal;
     ///
     /// let ctx = Context::new();
-    /// let a = Gen::from_str("12", &ctx)?;
     ///
-    /// assert_eq!(12, a.to_int()?);
+    /// assert_eq!(Decimal::new(125, 3), Gen::from_str("1/8", &ctx)?.to_decimal(3, &ctx)?);
+    /// assert_eq!(Decimal::new(3142, 3), Gen::from_str("pi", &ctx)?.to_decimal(3, &ctx)?);
+    /// assert!(Gen::from_str("10^30", &ctx)?.to_decimal(0, &ctx).is_err());
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node29.html>
-    pub fn from_str(str: &str, ctx: &Context) -> Result<Self, GiacError> {
-        let s = CString::new(str).unwrap();
-        ffi_safe_call! { ffi::giacrs_gen_from_str(s.as_ptr(), ctx.as_context_ref(), result.as_gen_ref()) }
+    #[cfg(feature = "rust-decimal")]
+    pub fn to_decimal(
+        &self,
+        scale: u32,
+        ctx: &Context,
+    ) -> Result<rust_decimal::Decimal, GiacError> {
+        let mantissa = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_gen_decimal_mantissa(
+                self.as_gen_ref(),
+                scale,
+                mantissa.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        // The mantissa fits in 96 bits, it has been checked by giac
+        let mantissa: i128 = mantissa.to_string().parse().unwrap();
+        Ok(rust_decimal::Decimal::from_i128_with_scale(mantissa, scale))
     }
 
-    /// Creates a `Gen` object from a factorial
+    /// Converts a floating point number d to a rational number q approaching d such that `abs(q-d) < epsilon` ([Context::set_epsilon])
     /// ```
+    /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
-    /// let value = Gen::factorial(24);
-    /// assert_eq!("620448401733239439360000", value.to_string());
+    /// let ctx = Context::new();
+    /// let a = Gen::from(0.125);
+    ///
+    /// assert_eq!("1/8", a.float_to_rational(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
     /// ```
-    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node28.html>
-    pub fn factorial(i: u64) -> Self {
-        Self(unsafe { ffi::giacrs_gen_factorial(i) })
+    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node61.html>
+    pub fn float_to_rational(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {ffi::giacrs_gen_float2rational(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
-    /// Returns the string representation of an expression.
-    /// This returns a [GiacString] object.
+    /// Converts all floating point numbers of an expression to nearby rational numbers (giac's `exact`),
+    /// with the same precision epsilon as [Gen::float_to_rational] ([Context::set_epsilon]).
+    /// Unlike [Gen::float_to_rational], whole expressions are accepted, like polynomials or matrices.
     /// ```
+    /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
-    /// assert_eq!("42", Gen::from(42).print_to_string().to_string());
-    /// assert_eq!("24", Gen::factorial(4).print_to_string().to_string());
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!("1/2", Gen::from(0.5).to_exact(&ctx)?.to_string());
+    /// assert_eq!("[[1/2,1/4],[3,1/8]]", Gen::from_str("[[0.5,0.25],[3.0,0.125]]", &ctx)?.to_exact(&ctx)?.to_string());
+    /// assert_eq!("x+1/4", Gen::from_str("x+0.25", &ctx)?.to_exact(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn print_to_string(&self) -> GiacString {
-        let str = unsafe { ffi::giacrs_gen_to_str(self.as_gen_ref()) };
-        unsafe { GiacString::new(str) }
+    pub fn to_exact(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_exact(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
-    /// Converts an expression to a primitive integer
+    /// Finds the best rational approximation of a number with a denominator bounded by `max_denom`.
+    ///
+    /// The number is first converted to an exact rational, then its continued fraction expansion is truncated
+    /// at the last convergent whose denominator doesn't exceed `max_denom`. This convergent is compared with
+    /// the semiconvergent with the largest denominator allowed, and the closest one is returned.
+    /// Unlike [Gen::float_to_rational], which depends on epsilon ([Context::set_epsilon]), the denominator is bounded directly.
     /// ```
+    /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
-    /// assert_eq!(42, Gen::from(42).to_int()?);
-    /// assert_eq!(24, Gen::factorial(4).to_int()?);
+    /// let ctx = Context::new();
+    /// let a = Gen::from(0.333333);
+    /// let pi = Gen::from(3.14159265358979);
+    ///
+    /// assert_eq!("1/3", a.rational_reconstruct(&10.into(), &ctx)?.to_string());
+    /// assert_eq!("22/7", pi.rational_reconstruct(&10.into(), &ctx)?.to_string());
+    /// // 311/99 is a semiconvergent, closer to pi than the convergent 22/7
+    /// assert_eq!("311/99", pi.rational_reconstruct(&100.into(), &ctx)?.to_string());
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn to_int(&self) -> Result<i32, GiacError> {
-        let mut result = 0;
-        let error = unsafe { ffi::giacrs_gen_to_int(self.as_gen_ref(), &mut result) };
-        if error == std::ptr::null() {
-            Ok(result)
-        } else {
-            Err(GiacError::InternalError(error.into()))
+    pub fn rational_reconstruct(&self, max_denom: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_rational_reconstruct(self.as_gen_ref(), max_denom.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
         }
     }
 
-    /// Returns the giac type of the expression. See [GenType]
+    /// Returns the numerator of a fraction, or of a rational function once normalized.
     /// ```
+    /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
-    /// use giacrs::types::GenType;
     ///
-    /// assert_eq!(GenType::Int, Gen::from(42).get_type());
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(363, Gen::from_str("363/28", &ctx)?.numer(&ctx)?.to_int()?);
+    /// assert_eq!("x+1", Gen::from_str("(x^2-1)/(x-1)^2", &ctx)?.numer(&ctx)?.to_string());
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn get_type(&self) -> GenType {
-        unsafe { ffi::giacrs_gen_type(self.as_gen_ref()) }
+    pub fn numer(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_numer(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
-    /// Checks if the expression equals zero
+    /// Returns the denominator of a fraction, or of a rational function once normalized.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
     /// let ctx = Context::new();
     ///
-    /// assert!(Gen::from(0).is_zero(&ctx)?);
-    /// assert!(!Gen::from(10).is_zero(&ctx)?);
+    /// assert_eq!(28, Gen::from_str("363/28", &ctx)?.denom(&ctx)?.to_int()?);
+    /// assert_eq!(1, Gen::from(5).denom(&ctx)?.to_int()?);
+    /// assert_eq!("x-1", Gen::from_str("(x^2-1)/(x-1)^2", &ctx)?.denom(&ctx)?.to_string());
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn is_zero(&self, ctx: &Context) -> Result<bool, GiacError> {
-        let mut result = false;
-        let error = unsafe {
-            ffi::giacrs_gen_is_zero(self.as_gen_ref(), &mut result, ctx.as_context_ref())
-        };
-        if error == std::ptr::null() {
-            Ok(result)
-        } else {
-            Err(GiacError::InternalError(error.into()))
-        }
+    pub fn denom(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_denom(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
-    /// Factorizes the expression.
+    /// Returns the sign of a real expression: `-1`, `0` or `1`.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
     /// let ctx = Context::new();
-    /// let poly = Gen::from_str("x^2-1", &ctx)?;
     ///
-    /// assert_eq!("(x-1)*(x+1)", poly.factor(&ctx)?.to_string());
+    /// assert_eq!(-1, Gen::from_str("-7/2", &ctx)?.sign(&ctx)?.to_int()?);
+    /// assert_eq!(0, Gen::from(0).sign(&ctx)?.to_int()?);
+    /// assert_eq!(1, Gen::from_str("pi-3", &ctx)?.sign(&ctx)?.to_int()?);
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn factor(&self, ctx: &Context) -> Result<Self, GiacError> {
-        ffi_safe_call! { ffi::giacrs_gen_factor(self.as_gen_ref(),result.as_gen_ref(), ctx.as_context_ref()) }
+    pub fn sign(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("sign", &[], ctx)
     }
 
-    /// Simplifies the expression.
+    /// Returns the greatest integer less than or equal to the expression.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
     /// let ctx = Context::new();
-    /// let poly = Gen::from_str("(x-1)*(x+1)", &ctx)?;
     ///
-    /// assert_eq!("x^2-1", poly.simplify(&ctx)?.to_string());
+    /// assert_eq!(3, Gen::from_str("7/2", &ctx)?.floor(&ctx)?.to_int()?);
+    /// assert_eq!(-4, Gen::from_str("-7/2", &ctx)?.floor(&ctx)?.to_int()?);
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    pub fn simplify(&self, ctx: &Context) -> Result<Self, GiacError> {
-        ffi_safe_call! { ffi::giacrs_gen_simplify(self.as_gen_ref(),result.as_gen_ref(), ctx.as_context_ref()) }
+    pub fn floor(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("floor", &[], ctx)
     }
 
-    /// Computes the determinant of the matrix.
+    /// Returns the smallest integer greater than or equal to the expression.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
     /// let ctx = Context::new();
-    /// let mat = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
     ///
-    /// assert_eq!(-2, mat.det(&ctx)?.to_int()?);
+    /// assert_eq!(4, Gen::from_str("7/2", &ctx)?.ceil(&ctx)?.to_int()?);
+    /// assert_eq!(-3, Gen::from_str("-7/2", &ctx)?.ceil(&ctx)?.to_int()?);
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-
-    pub fn det(&self, ctx: &Context) -> Result<Self, GiacError> {
-        ffi_safe_call! { ffi::giacrs_gen_det(self.as_gen_ref(),result.as_gen_ref(), ctx.as_context_ref()) }
+    pub fn ceil(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("ceil", &[], ctx)
     }
 
-    // GIAC METHODS
+    /// Returns the nearest integer to the expression.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(3, Gen::from_str("13/5", &ctx)?.round(&ctx)?.to_int()?);
+    /// assert_eq!(-3, Gen::from_str("-27/10", &ctx)?.round(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn round(&self, ctx: &Context) -> Result<Self, GiacError> {
+        self.apply("round", &[], ctx)
+    }
 
-    /// Converts a floating point number d to a rational number q approaching d such that `abs(q-d) < epsilon` ([Context::set_epsilon])
+    /// Splits a rational number into a mixed number, returned as `(integer_part, numerator, denominator)`
+    /// such that `self = integer_part + numerator/denominator` with `0 <= |numerator| < denominator`.
+    ///
+    /// The denominator is always positive, and the sign of a negative fraction is carried by both the integer part
+    /// and the numerator: `-7/2` gives `(-3, -1, 2)`. When the integer part is zero, the sign is only on the numerator.
+    /// An error is returned if the expression isn't a rational number.
     /// ```
     /// use giacrs::context::Context;
     /// use giacrs::gen::Gen;
     ///
     /// let ctx = Context::new();
-    /// let a = Gen::from(0.125);
+    /// let to_ints = |(a, b, c): (Gen, Gen, Gen)| -> Result<_, GiacError> {
+    ///     Ok((a.to_int()?, b.to_int()?, c.to_int()?))
+    /// };
     ///
-    /// assert_eq!("1/8", a.float_to_rational(&ctx)?.to_string());
+    /// assert_eq!((3, 1, 2), to_ints(Gen::from_str("7/2", &ctx)?.to_mixed_number(&ctx)?)?);
+    /// assert_eq!((-3, -1, 2), to_ints(Gen::from_str("-7/2", &ctx)?.to_mixed_number(&ctx)?)?);
+    /// assert_eq!((0, -1, 3), to_ints(Gen::from_str("-1/3", &ctx)?.to_mixed_number(&ctx)?)?);
+    /// assert_eq!((4, 0, 1), to_ints(Gen::from(4).to_mixed_number(&ctx)?)?);
+    /// assert!(Gen::from_str("x/2", &ctx)?.to_mixed_number(&ctx).is_err());
     /// # use giacrs::GiacError;
     /// # Ok::<(), GiacError>(())
     /// ```
-    /// <https://www-fourier.ujf-grenoble.fr/~parisse/giac/doc/en/cascmd_en/node61.html>
-    pub fn float_to_rational(&self, ctx: &Context) -> Result<Self, GiacError> {
-        ffi_safe_call! {ffi::giacrs_gen_float2rational(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    pub fn to_mixed_number(&self, ctx: &Context) -> Result<(Self, Self, Self), GiacError> {
+        let integer_part = Self::new();
+        let numerator = Self::new();
+        let denominator = Self::new();
+        let error = unsafe {
+            ffi::giacrs_gen_to_mixed_number(
+                self.as_gen_ref(),
+                integer_part.as_gen_ref(),
+                numerator.as_gen_ref(),
+                denominator.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok((integer_part, numerator, denominator))
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
     }
 }
 
+/// Never fails, the whole `u64` range is supported.
+/// ```
+/// use giacrs::gen::Gen;
+///
+/// assert_eq!("9000000000", Gen::try_from(9_000_000_000u64)?.to_string());
+/// assert_eq!("18446744073709551615", Gen::try_from(u64::MAX)?.to_string());
+/// assert_eq!("9223372036854775808", Gen::try_from(1u64 << 63)?.to_string());
+/// # Ok::<(), std::num::TryFromIntError>(())
+/// ```
 impl TryFrom<u64> for Gen {
     type Error = TryFromIntError;
     fn try_from(value: u64) -> Result<Self, Self::Error> {
-        Ok(Self(unsafe { ffi::giacrs_gen_from_int(value.try_into()?) }))
+        Ok(Self(unsafe { ffi::giacrs_gen_from_ulonglong(value) }))
     }
 }
 
@@ -224,10 +1988,11 @@ impl From<u8> for Gen {
     }
 }
 
+/// Never fails, the whole `i64` range is supported.
 impl TryFrom<i64> for Gen {
     type Error = TryFromIntError;
     fn try_from(value: i64) -> Result<Self, Self::Error> {
-        Ok(Self(unsafe { ffi::giacrs_gen_from_int(value.try_into()?) }))
+        Ok(Self(unsafe { ffi::giacrs_gen_from_longlong(value) }))
     }
 }
 
@@ -275,12 +2040,179 @@ impl Display for Gen {
     }
 }
 
+/// Compares the string representations of the expressions ([Gen::print_to_string]), so the comparison is structural:
+/// mathematically equal expressions with different forms, like `(x+1)^2` and `x^2+2*x+1`, or `1` and `1.0`, are different.
+/// Simplify expressions first to compare them mathematically, or check that their difference [is zero](Gen::is_zero).
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+///
+/// assert_eq!(Gen::from_str("2*x", &ctx)?, Gen::from_str("x+x", &ctx)?);
+/// assert_ne!(Gen::from_str("(x+1)^2", &ctx)?, Gen::from_str("x^2+2*x+1", &ctx)?);
+/// assert_ne!(Gen::from(1), Gen::from(1.0));
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+impl PartialEq for Gen {
+    fn eq(&self, other: &Self) -> bool {
+        self.print_to_string() == other.print_to_string()
+    }
+}
+
+impl Eq for Gen {}
+
+/// Hashes the string representation of the expression ([Gen::print_to_string]), consistently with [PartialEq].
+/// The expression is converted to a string each time it is hashed, which can be slow for large expressions.
+/// ```
+/// use std::collections::HashMap;
+///
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let mut cache = HashMap::new();
+/// let e = Gen::from_str("(x+1)^2", &ctx)?;
+///
+/// cache.insert(e.clone(), e.factor(&ctx)?);
+/// assert!(cache.contains_key(&Gen::from_str("(x+1)^2", &ctx)?));
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+impl Hash for Gen {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.print_to_string().to_bytes().hash(state);
+    }
+}
+
+/// Serializes an expression to its string representation ([Gen::print_to_string]).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Gen {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes an expression from a string, parsed and evaluated in the [GLOBAL_CONTEXT](crate::context::GLOBAL_CONTEXT).
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use serde::{de::IntoDeserializer, Deserialize};
+///
+/// let ctx = Context::new();
+/// let e = Gen::from_str("x^2+1", &ctx)?;
+///
+/// let result: Result<Gen, serde::de::value::Error> = Gen::deserialize(e.to_string().into_deserializer());
+/// assert!((result.unwrap() - &e).is_zero(&ctx)?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Gen {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let str = String::deserialize(deserializer)?;
+        Gen::from_str(&str, &crate::context::GLOBAL_CONTEXT).map_err(serde::de::Error::custom)
+    }
+}
+
 impl Clone for Gen {
     fn clone(&self) -> Self {
         Self(unsafe { ffi::giacrs_gen_clone(self.as_gen_ref()) })
     }
 }
 
+/// Arithmetic operations returning an error instead of panicking like the operators
+impl Gen {
+    fn checked_operation(
+        &self,
+        rhs: &Gen,
+        operation: unsafe extern "C" fn(GiacGenRef, GiacGenRef) -> ffi::GiacResult,
+    ) -> Result<Self, GiacError> {
+        let result = self.clone();
+        let error = unsafe { operation(result.as_gen_ref(), rhs.as_gen_ref()) };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Adds two expressions, like the `+` operator which panics if giac throws an error.
+    /// Unlike the operator, vectors and matrices must have the same dimensions, where giac would pad the shortest one with zeros.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1,2],[3,4]]", &ctx)?;
+    ///
+    /// assert_eq!("[[2,4],[6,8]]", a.checked_add(&a)?.to_string());
+    /// // Mismatched dimensions
+    /// let b = Gen::from_str("[[1,2,3],[4,5,6]]", &ctx)?;
+    /// assert!(a.checked_add(&b).is_err());
+    /// assert!(Gen::from_str("[1,2]", &ctx)?.checked_add(&Gen::from_str("[1,2,3]", &ctx)?).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn checked_add(&self, rhs: &Gen) -> Result<Self, GiacError> {
+        self.checked_operation(rhs, ffi::giacrs_gen_checked_add)
+    }
+
+    /// Subtracts two expressions, like the `-` operator which panics if giac throws an error.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("x+2", &ctx)?;
+    ///
+    /// assert_eq!("x", a.checked_sub(&2.into())?.to_string());
+    /// // Mismatched dimensions
+    /// let v = Gen::from_str("[1,2]", &ctx)?;
+    /// assert!(v.checked_sub(&Gen::from_str("[1,2,3]", &ctx)?).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn checked_sub(&self, rhs: &Gen) -> Result<Self, GiacError> {
+        self.checked_operation(rhs, ffi::giacrs_gen_checked_sub)
+    }
+
+    /// Multiplies two expressions, like the `*` operator which panics if giac throws an error.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from_str("[[1,2]]", &ctx)?;
+    ///
+    /// assert_eq!("[[5]]", a.checked_mul(&a.transpose(&ctx)?)?.to_string());
+    /// // Inconsistent dimensions
+    /// assert!(a.checked_mul(&a).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn checked_mul(&self, rhs: &Gen) -> Result<Self, GiacError> {
+        self.checked_operation(rhs, ffi::giacrs_gen_mul)
+    }
+
+    /// Divides two expressions, like the `/` operator which panics if giac throws an error.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from(6);
+    ///
+    /// assert_eq!(3, a.checked_div(&2.into())?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn checked_div(&self, rhs: &Gen) -> Result<Self, GiacError> {
+        self.checked_operation(rhs, ffi::giacrs_gen_div)
+    }
+}
+
 impl Add<&Self> for Gen {
     type Output = Gen;
 
@@ -337,6 +2269,89 @@ impl DivAssign<&Self> for Gen {
     }
 }
 
+/// Sums expressions, starting from `0`.
+/// ```
+/// use giacrs::gen::Gen;
+///
+/// let values = [Gen::from(1), Gen::from(2), Gen::from(3)];
+///
+/// assert_eq!(6, values.iter().sum::<Gen>().to_int()?);
+/// assert_eq!(6, values.into_iter().sum::<Gen>().to_int()?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+impl Sum for Gen {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gen::from(0), |acc, x| acc + &x)
+    }
+}
+impl<'a> Sum<&'a Gen> for Gen {
+    fn sum<I: Iterator<Item = &'a Gen>>(iter: I) -> Self {
+        iter.fold(Gen::from(0), |acc, x| acc + x)
+    }
+}
+
+/// Multiplies expressions, starting from `1`.
+/// ```
+/// use giacrs::gen::Gen;
+///
+/// let values = [Gen::from(2), Gen::from(3), Gen::from(4)];
+///
+/// assert_eq!(24, values.iter().product::<Gen>().to_int()?);
+/// assert_eq!(24, values.into_iter().product::<Gen>().to_int()?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+impl Product for Gen {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gen::from(1), |acc, x| acc * &x)
+    }
+}
+impl<'a> Product<&'a Gen> for Gen {
+    fn product<I: Iterator<Item = &'a Gen>>(iter: I) -> Self {
+        iter.fold(Gen::from(1), |acc, x| acc * x)
+    }
+}
+
+/// Iterates over the elements of a vector.
+///
+/// # Panics
+/// Panics if the expression isn't a vector.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+///
+/// let ctx = Context::new();
+/// let v = Gen::from_str("[1,2,3]", &ctx)?;
+///
+/// let mut values = Vec::new();
+/// for elem in &v {
+///     values.push(elem.to_int()?);
+/// }
+/// assert_eq!(vec![1, 2, 3], values);
+/// assert_eq!(6, v.into_iter().sum::<Gen>().to_int()?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+impl IntoIterator for Gen {
+    type Item = Gen;
+    type IntoIter = std::vec::IntoIter<Gen>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self).into_iter()
+    }
+}
+impl IntoIterator for &Gen {
+    type Item = Gen;
+    type IntoIter = std::vec::IntoIter<Gen>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_vec()
+            .unwrap_or_else(|err| panic!("{:?}", err))
+            .into_iter()
+    }
+}
+
 // TODO: implement remainder, but it's not the same as irem in giac
 // impl Rem<&Self> for Gen {
 //     type Output = Gen;
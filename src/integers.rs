@@ -3,7 +3,12 @@
 
 use giacrs_internals::ffi_safe_call;
 
-use crate::{context::Context, ffi, gen::Gen, GiacError};
+use crate::{
+    context::Context,
+    ffi,
+    gen::{Gen, GiacGenRef},
+    GiacError,
+};
 
 /// Return value of the [Gen::is_pseudoprime] method
 #[derive(Debug, PartialEq, Eq)]
@@ -279,6 +284,34 @@ impl Gen {
         }
     }
 
+    /// Returns true if the number is prime, false otherwise.
+    /// Unlike [Gen::is_pseudoprime], the answer is certain: giac proves primality instead of relying on probabilistic tests.
+    /// This can be slow for very large numbers, prefer [Gen::is_pseudoprime] if a fast answer is enough.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert!(Gen::from(100003).is_prime(&ctx)?);
+    /// assert!(!Gen::from(14).is_prime(&ctx)?);
+    /// // 561 is a Carmichael number
+    /// assert!(!Gen::from(561).is_prime(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_prime(&self, ctx: &Context) -> Result<bool, GiacError> {
+        let mut result = false;
+        let error = unsafe {
+            ffi::giacrs_gen_is_prime(self.as_gen_ref(), &mut result, ctx.as_context_ref())
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
     /// Returns the smallest pseudoprime ([PseudoPrime]) number greater than self.
     /// ```
     /// use giacrs::gen::Gen;
@@ -326,6 +359,54 @@ impl Gen {
         ffi_safe_call! { ffi::giacrs_gen_nthprime(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
 
+    /// Returns the number of primes less than or equal to self, the inverse of [Gen::nth_prime].
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(25, Gen::from(100).prime_pi(&ctx)?.to_int()?);
+    /// assert_eq!(75, Gen::from(379).prime_pi(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn prime_pi(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_prime_pi(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the nth Fibonacci number with giac's `fibonacci`, where `F(0) = 0` and `F(1) = 1`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(55, Gen::fibonacci(10, &ctx)?.to_int()?);
+    /// assert_eq!("354224848179261915075", Gen::fibonacci(100, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn fibonacci(n: u64, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_fibonacci(n, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the nth Lucas number with giac's `lucas`, where `L(0) = 2` and `L(1) = 1`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(123, Gen::lucas(10, &ctx)?.to_int()?);
+    /// assert_eq!("792070839848372253127", Gen::lucas(100, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn lucas(n: u64, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_lucas(n, result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
     /// Returns the coefficients of the Bézout's identity.
     /// Returns `(u, v, d)` where `u*a + v*b = d` and `d=gcd(a,b)`
     /// ```
@@ -362,6 +443,55 @@ impl Gen {
         }
     }
 
+    /// Computes `self^exp mod modulus` without computing the full power.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from(7);
+    ///
+    /// assert_eq!(9, a.powmod(&100000.into(), &13.into(), &ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn powmod(&self, exp: &Gen, modulus: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_powmod(
+                self.as_gen_ref(),
+                exp.as_gen_ref(),
+                modulus.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        }
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, i.e. the integer `0 <= u < modulus` such that `self*u = 1 mod modulus`.
+    /// It is derived from the Bézout's coefficients ([Gen::iegcd]), and only exists if `self` and `modulus` are coprime.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let a = Gen::from(3);
+    ///
+    /// // 3*5 = 15 = 1 mod 7
+    /// assert_eq!(5, a.invmod(&7.into(), &ctx)?.to_int()?);
+    /// // gcd(3, 6) = 3
+    /// assert!(a.invmod(&6.into(), &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn invmod(&self, modulus: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        let (u, _, d) = self.iegcd(modulus)?;
+        if !(d - &1.into()).is_zero(ctx)? {
+            return Err(GiacError::NoSolution("self and modulus must be coprime"));
+        }
+        // u may be negative
+        (u.irem(modulus)? + modulus).irem(modulus)
+    }
+
     /// Solves `a² + b² = p`. `p` must be congruent to 1 modulo 4.
     /// ```
     /// use giacrs::context::Context;
@@ -418,6 +548,55 @@ impl Gen {
     pub fn euler(&self, ctx: &Context) -> Result<Self, GiacError> {
         ffi_safe_call! { ffi::giacrs_gen_euler(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
     }
+
+    /// Returns the Möbius function of a positive integer:
+    /// `0` if it has a squared prime factor, `(-1)^k` if it is the product of `k` distinct primes.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(0, Gen::from(12).moebius(&ctx)?.to_int()?);
+    /// assert_eq!(-1, Gen::from(30).moebius(&ctx)?.to_int()?);
+    /// assert_eq!(1, Gen::from(1).moebius(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn moebius(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_moebius(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the sum of the divisors of a positive integer.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// // 1+2+3+4+6+12
+    /// assert_eq!(28, Gen::from(12).sigma(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sigma(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_sigma(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the number of divisors of a positive integer.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    ///
+    /// assert_eq!(6, Gen::from(12).tau(&ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn tau(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_tau(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
 }
 
 /// Solve the equation `u*a + v*b = c`.
@@ -497,6 +676,51 @@ pub fn ichinrem(a: &Gen, amod: &Gen, b: &Gen, bmod: &Gen) -> Result<(Gen, Gen),
     }
 }
 
+/// Computes the chinese remainders of a list of congruences, by folding [ichinrem] over them.
+/// Returns `(c, m)` where `m` is the lcm of all `moduli`, such that every `d = c + k*m` satisfies `d=residues[i][moduli[i]]` for all `i`.
+/// A [GiacError::NoSolution] is returned if the slices don't have the same length, or if the congruences are contradictory.
+/// An empty list of congruences gives `(0, 1)`.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::integers::ichinrem_list;
+///
+/// let ctx = Context::new();
+/// let residues = [Gen::from(2), Gen::from(3), Gen::from(2)];
+/// let moduli = [Gen::from(3), Gen::from(5), Gen::from(7)];
+/// let (c, m) = ichinrem_list(&residues, &moduli, &ctx)?;
+/// assert_eq!(105, m.to_int()?);
+/// assert_eq!(23, c.irem(&m)?.to_int()?.rem_euclid(105));
+///
+/// // x=1[2] and x=0[4] can't both hold
+/// let residues = [Gen::from(1), Gen::from(0)];
+/// let moduli = [Gen::from(2), Gen::from(4)];
+/// assert!(ichinrem_list(&residues, &moduli, &ctx).is_err());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn ichinrem_list(
+    residues: &[Gen],
+    moduli: &[Gen],
+    ctx: &Context,
+) -> Result<(Gen, Gen), GiacError> {
+    if residues.len() != moduli.len() {
+        return Err(GiacError::NoSolution(
+            "residues and moduli must have the same length",
+        ));
+    }
+    let mut acc = (Gen::from(0), Gen::from(1));
+    for (b, bmod) in residues.iter().zip(moduli) {
+        let (a, amod) = &acc;
+        let d = amod.gcd(bmod, ctx)?;
+        if !((a.clone() - b).irem(&d)?).is_zero(ctx)? {
+            return Err(GiacError::NoSolution("the congruences are contradictory"));
+        }
+        acc = ichinrem(a, amod, b, bmod)?;
+    }
+    Ok(acc)
+}
+
 /// Computes the Legendre symbol
 /// ```
 /// use giacrs::gen::Gen;
@@ -540,3 +764,41 @@ pub fn jacobi_symbol(a: &Gen, n: &Gen) -> Result<i8, GiacError> {
         Err(GiacError::InternalError(error.into()))
     }
 }
+
+/// Factorizes every integer of `values`, like [Gen::ifactor], in a single call to giac.
+/// The values are still factored one after the other, only the FFI round-trips are saved,
+/// see the `ifactor_batch` benchmark to compare with a loop.
+/// The results are returned in the order of `values`.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::integers::ifactor_batch;
+///
+/// let ctx = Context::new();
+/// let values = [Gen::from(90), Gen::from(17), Gen::from(1024)];
+/// let factors = ifactor_batch(&values, &ctx)?;
+///
+/// assert_eq!("2*3^2*5", factors[0].to_string());
+/// assert_eq!("17", factors[1].to_string());
+/// assert_eq!("2^10", factors[2].to_string());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn ifactor_batch(values: &[Gen], ctx: &Context) -> Result<Vec<Gen>, GiacError> {
+    let results: Vec<Gen> = values.iter().map(|_| Gen::new()).collect();
+    let inputs: Vec<GiacGenRef> = values.iter().map(|v| unsafe { v.as_gen_ref() }).collect();
+    let outputs: Vec<GiacGenRef> = results.iter().map(|r| unsafe { r.as_gen_ref() }).collect();
+    let error = unsafe {
+        ffi::giacrs_gen_ifactor_batch(
+            inputs.as_ptr(),
+            outputs.as_ptr(),
+            values.len(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(results)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
@@ -4,11 +4,16 @@
 pub mod context;
 mod ffi;
 pub mod gen;
+mod macros;
 
 mod support;
 pub use support::*;
 pub mod options;
 pub mod types;
 
+pub mod calculus;
 pub mod combinatory;
+pub mod equations;
+pub mod functions;
 pub mod integers;
+pub mod polynomial;
@@ -0,0 +1,36 @@
+//! Macros to build expressions
+
+/// Builds an expression from a format string, like [format!], and parses it with [Gen::from_str](crate::gen::Gen::from_str).
+/// Returns a `Result<Gen, GiacError>`.
+///
+/// Arguments are interpolated with their string representation, surrounded by parentheses
+/// so the precedence of operators is kept, like `x^(a+1)` for `gen!("x^{}", a+1)`.
+/// The context can be given after a `;`, otherwise the [GLOBAL_CONTEXT](crate::context::GLOBAL_CONTEXT) is used.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::gen;
+///
+/// let ctx = Context::new();
+/// let value = Gen::from_str("a+1", &ctx)?;
+///
+/// let e = gen!("x^2+{}", value; ctx)?;
+/// assert_eq!("x^2+a+1", e.to_string());
+/// let f = gen!("2^{}", value; ctx)?;
+/// assert_eq!("2^(a+1)", f.to_string());
+/// assert_eq!(6, gen!("{}*{}", 2, 3)?.to_int()?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+#[macro_export]
+macro_rules! gen {
+    ($fmt:literal $(, $arg:expr)* ; $ctx:expr) => {
+        $crate::gen::Gen::from_str(&format!($fmt $(, format_args!("({})", $arg))*), &$ctx)
+    };
+    ($fmt:literal $(, $arg:expr)*) => {
+        $crate::gen::Gen::from_str(
+            &format!($fmt $(, format_args!("({})", $arg))*),
+            &$crate::context::GLOBAL_CONTEXT,
+        )
+    };
+}
@@ -1,6 +1,6 @@
 //! Edit giac contexts options
 
-use crate::{context::Context, ffi};
+use crate::{context::Context, ffi, types::AngleMode};
 
 impl Context {
     /// Change epsilon value, used for choosing a precision in computations.
@@ -22,4 +22,115 @@ impl Context {
     pub fn set_epsilon(&mut self, epsilon: f64) {
         unsafe { ffi::giacrs_options_set_epsilon(epsilon, self.as_context_ref()) };
     }
+
+    /// Change the number of significant digits used for approximate computations, like [Gen::evalf](crate::gen::Gen::evalf).
+    /// [Gen::approx](crate::gen::Gen::approx) isn't affected, since it takes its own number of digits.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    /// let pi = Gen::from_str("pi", &ctx)?;
+    ///
+    /// ctx.set_digits(20);
+    /// assert!(pi.evalf(&ctx)?.to_string().starts_with("3.14159265358979323"));
+    /// assert!(Gen::from_str("evalf(pi)", &ctx)?.to_string().starts_with("3.14159265358979323"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn set_digits(&mut self, digits: u32) {
+        unsafe { ffi::giacrs_options_set_digits(digits, self.as_context_ref()) };
+    }
+
+    /// Enable or disable the approximate mode, disabled by default.
+    /// When enabled, all subsequent evaluations in this context return floating point results instead of exact values,
+    /// until it is disabled again. Other contexts aren't affected.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::GenType;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.set_approx_mode(true);
+    /// assert_eq!(GenType::Double, ctx.eval("1/3")?.get_type());
+    /// assert_eq!(GenType::Double, ctx.eval("sqrt(2)")?.get_type());
+    ///
+    /// ctx.set_approx_mode(false);
+    /// assert_eq!("1/3", ctx.eval("1/3")?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn set_approx_mode(&mut self, on: bool) {
+        unsafe { ffi::giacrs_options_set_approx_mode(on, self.as_context_ref()) };
+    }
+
+    /// Enable or disable the complex mode, disabled by default.
+    /// When enabled, computations are done in the complex numbers, so the square root of a negative number is imaginary
+    /// and polynomials are factored over the complex numbers.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.set_complex_mode(true);
+    /// assert_eq!("i", Gen::from(-1).sqrt(&ctx)?.to_string());
+    /// // x^2+1 = (x-i)*(x+i)
+    /// let factors = Gen::from_str("x^2+1", &ctx)?.factor(&ctx)?;
+    /// assert_eq!("*", factors.operator_name().unwrap().to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn set_complex_mode(&mut self, on: bool) {
+        unsafe { ffi::giacrs_options_set_complex_mode(on, self.as_context_ref()) };
+    }
+
+    /// Change the unit used for angles, radians by default.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::AngleMode;
+    ///
+    /// let mut ctx = Context::new();
+    ///
+    /// ctx.set_angle_mode(AngleMode::Degree);
+    /// let a = Gen::from_str("sin(90)", &ctx)?.approx(10, &ctx)?;
+    /// assert!((a - &1.into()).is_zero(&ctx)?);
+    ///
+    /// ctx.set_angle_mode(AngleMode::Radian);
+    /// let b = Gen::from_str("sin(pi/2)", &ctx)?;
+    /// assert_eq!(1, b.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn set_angle_mode(&mut self, mode: AngleMode) {
+        unsafe {
+            ffi::giacrs_options_set_angle_radian(mode == AngleMode::Radian, self.as_context_ref())
+        };
+    }
+
+    /// Seed the random generator of this context, used by functions like [Gen::rand](crate::gen::Gen::rand).
+    /// The seed is stored in the context, so each context has its own sequence and drawing from one doesn't change the others:
+    /// two contexts seeded identically produce the same random sequence.
+    /// Giac seeds are 31 bits integers, so the seed is folded before being used.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let mut a = Context::new();
+    /// let mut b = Context::new();
+    /// let n = Gen::from(1000000);
+    ///
+    /// a.set_random_seed(42);
+    /// b.set_random_seed(42);
+    /// for _ in 0..5 {
+    ///     assert_eq!(n.rand(&a)?.to_int()?, n.rand(&b)?.to_int()?);
+    /// }
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn set_random_seed(&mut self, seed: u64) {
+        unsafe { ffi::giacrs_options_set_random_seed(seed, self.as_context_ref()) };
+    }
 }
@@ -0,0 +1,660 @@
+//! Polynomials
+
+use giacrs_internals::ffi_safe_call;
+
+use crate::{context::Context, ffi, gen::Gen, GiacError};
+
+/// Computes the modular composition `f(g(var)) mod h(var)`.
+/// `var` is the variable in which `f`, `g` and `h` are polynomials, and `h` is the modulus polynomial.
+///
+/// The composition is computed with a Horner scheme reducing modulo `h` at each step,
+/// so `f(g(var))` is never fully expanded.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::polynomial::poly_compose_mod;
+///
+/// let ctx = Context::new();
+/// let x = Gen::from_str("x", &ctx)?;
+/// let f = Gen::from_str("x^2", &ctx)?;
+/// let g = Gen::from_str("x+1", &ctx)?;
+/// let h = Gen::from_str("x^2+1", &ctx)?;
+///
+/// // (x+1)^2 = x^2+2*x+1 = 2*x mod x^2+1
+/// assert_eq!("2*x", poly_compose_mod(&f, &g, &h, &x, &ctx)?.to_string());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn poly_compose_mod(
+    f: &Gen,
+    g: &Gen,
+    h: &Gen,
+    var: &Gen,
+    ctx: &Context,
+) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_poly_compose_mod(
+            f.as_gen_ref(),
+            g.as_gen_ref(),
+            h.as_gen_ref(),
+            var.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Computes the rational function interpolating the points `(xs[i], ys[i])` in the variable `var`, using Thiele's continued fractions.
+/// Unlike polynomial interpolation, this can fit data sampled from a function with poles.
+///
+/// `xs` and `ys` must be vectors of the same length, and the nodes in `xs` must be pairwise distinct.
+/// An error is returned when no rational interpolant exists for the data (an inverse difference is infinite).
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::polynomial::rational_interp;
+///
+/// let ctx = Context::new();
+/// let x = Gen::from_str("x", &ctx)?;
+/// let xs = Gen::from_str("[0,1,2]", &ctx)?;
+/// // Sampled from 1/(x+1)
+/// let ys = Gen::from_str("[1,1/2,1/3]", &ctx)?;
+///
+/// let r = rational_interp(&xs, &ys, &x, &ctx)?;
+/// let expected = Gen::from_str("1/(x+1)", &ctx)?;
+/// assert!((r - &expected).simplify(&ctx)?.is_zero(&ctx)?);
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn rational_interp(xs: &Gen, ys: &Gen, var: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_thiele(
+            xs.as_gen_ref(),
+            ys.as_gen_ref(),
+            var.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Computes the `k`th elementary symmetric polynomial in the variables `vars`,
+/// i.e. the sum of all products of `k` distinct variables of `vars`.
+///
+/// `vars` is a vector of variables. `k=0` gives `1`, and `k=1` gives the sum of the variables.
+/// An error is returned if `k` exceeds the number of variables.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::polynomial::symmetric_poly;
+///
+/// let ctx = Context::new();
+/// let vars = Gen::from_str("[x,y,z]", &ctx)?;
+///
+/// assert_eq!(1, symmetric_poly(0, &vars, &ctx)?.to_int()?);
+/// assert_eq!("x+y+z", symmetric_poly(1, &vars, &ctx)?.to_string());
+/// assert_eq!("x*y+x*z+y*z", symmetric_poly(2, &vars, &ctx)?.to_string());
+/// assert_eq!("x*y*z", symmetric_poly(3, &vars, &ctx)?.to_string());
+/// assert!(symmetric_poly(4, &vars, &ctx).is_err());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn symmetric_poly(k: u32, vars: &Gen, ctx: &Context) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_symmetric_poly(
+            k,
+            vars.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+/// Computes the Hermite interpolation polynomial in the variable `var`, the polynomial of lowest degree
+/// taking the value `values[i]` and the derivative `derivs[i]` at the node `nodes[i]`.
+///
+/// `nodes`, `values` and `derivs` must be vectors of the same length `n`, aligned by index, and the nodes must be pairwise distinct.
+/// The result has degree at most `2n-1`.
+/// ```
+/// use giacrs::context::Context;
+/// use giacrs::gen::Gen;
+/// use giacrs::polynomial::hermite_interp;
+///
+/// let ctx = Context::new();
+/// let x = Gen::from_str("x", &ctx)?;
+/// let nodes = Gen::from_str("[0,1]", &ctx)?;
+/// // Sampled from x^3: values and derivatives
+/// let values = Gen::from_str("[0,1]", &ctx)?;
+/// let derivs = Gen::from_str("[0,3]", &ctx)?;
+///
+/// let p = hermite_interp(&nodes, &values, &derivs, &x, &ctx)?;
+/// assert_eq!("x^3", p.to_string());
+/// assert!(hermite_interp(&nodes, &values, &Gen::from_str("[0]", &ctx)?, &x, &ctx).is_err());
+/// # use giacrs::GiacError;
+/// # Ok::<(), GiacError>(())
+/// ```
+pub fn hermite_interp(
+    nodes: &Gen,
+    values: &Gen,
+    derivs: &Gen,
+    var: &Gen,
+    ctx: &Context,
+) -> Result<Gen, GiacError> {
+    let result = Gen::new();
+    let error = unsafe {
+        ffi::giacrs_gen_hermite_interp(
+            nodes.as_gen_ref(),
+            values.as_gen_ref(),
+            derivs.as_gen_ref(),
+            var.as_gen_ref(),
+            result.as_gen_ref(),
+            ctx.as_context_ref(),
+        )
+    };
+    if error == std::ptr::null() {
+        Ok(result)
+    } else {
+        Err(GiacError::InternalError(error.into()))
+    }
+}
+
+impl Gen {
+    /// Returns the coefficients of the polynomial `self(x0 + var)` in `var`, i.e. the Taylor coefficients of `self` around `x0`.
+    /// Coefficients are ordered from the lowest to the highest degree.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^2", &ctx)?;
+    ///
+    /// // (1+x)^2 = 1 + 2*x + x^2
+    /// let coeffs = p.shift_poly_coeffs(&x, &1.into(), &ctx)?;
+    /// let coeffs: Vec<i32> = coeffs.iter().map(|c| c.to_int()).collect::<Result<_, _>>()?;
+    /// assert_eq!(vec![1, 2, 1], coeffs);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn shift_poly_coeffs(
+        &self,
+        var: &Gen,
+        x0: &Gen,
+        ctx: &Context,
+    ) -> Result<Vec<Gen>, GiacError> {
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_gen_shift_poly_coeffs(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                x0.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns a vector of numeric approximations of the (possibly complex) roots of a univariate polynomial.
+    /// The polynomial can be given as a symbolic expression in a single variable, or as a vector of coefficients from the highest to the lowest degree.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::GenType;
+    ///
+    /// let ctx = Context::new();
+    /// let p = Gen::from_str("x^2+1", &ctx)?;
+    /// let q = Gen::from_str("[1,0,1]", &ctx)?;
+    ///
+    /// let roots = p.proot(&ctx)?;
+    /// assert_eq!(GenType::Vector, roots.get_type());
+    /// assert!(roots.to_string().contains("i"));
+    /// assert_eq!(roots.to_string(), q.proot(&ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn proot(&self, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! { ffi::giacrs_gen_proot(self.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref()) }
+    }
+
+    /// Returns the degree of the polynomial in the variable `var`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^3+2*x+5", &ctx)?;
+    ///
+    /// assert_eq!(3, p.degree(&x, &ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn degree(&self, var: &Gen, ctx: &Context) -> Result<i32, GiacError> {
+        let mut result = 0;
+        let error = unsafe {
+            ffi::giacrs_gen_degree(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                &mut result,
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the coefficient of `var^n` in the polynomial.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^3+2*x+5", &ctx)?;
+    ///
+    /// assert_eq!(2, p.coeff(&x, 1, &ctx)?.to_int()?);
+    /// assert_eq!(0, p.coeff(&x, 2, &ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn coeff(&self, var: &Gen, n: u32, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_coeff(self.as_gen_ref(), var.as_gen_ref(), n, result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Returns the vector of the coefficients of the polynomial in the variable `var`, from the highest to the lowest degree.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^3+2*x+5", &ctx)?;
+    ///
+    /// assert_eq!("[1,0,2,5]", p.coeffs(&x, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn coeffs(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_coeffs(self.as_gen_ref(), var.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Returns the non-zero coefficients of a polynomial in the variable `var`, numerically evaluated,
+    /// as `(degree, coefficient)` pairs ordered from the highest degree to the lowest.
+    ///
+    /// An error is returned if a coefficient can't be evaluated to a real number,
+    /// e.g. if it contains another variable or is complex.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// assert_eq!(vec![(3, 2.5), (1, 1.0)], Gen::from_str("2.5*x^3+x", &ctx)?.numeric_coeffs(&x, &ctx)?);
+    /// assert_eq!(vec![(1, 0.5), (0, 2f64.sqrt())], Gen::from_str("x/2+sqrt(2)", &ctx)?.numeric_coeffs(&x, &ctx)?);
+    /// assert!(Gen::from_str("y*x+1", &ctx)?.numeric_coeffs(&x, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn numeric_coeffs(&self, var: &Gen, ctx: &Context) -> Result<Vec<(i64, f64)>, GiacError> {
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_gen_numeric_coeffs(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        result
+            .to_vec()?
+            .into_iter()
+            .map(|pair| {
+                let pair = pair.to_vec()?;
+                Ok((pair[0].to_int()?.into(), pair[1].to_f64()?))
+            })
+            .collect()
+    }
+
+    /// Converts a polynomial in the variable `var` to the Bernstein basis on `[0, 1]`, returning its Bézier control coefficients.
+    ///
+    /// The degree `n` is the degree of the polynomial in `var`, and the `n+1` returned coefficients `b_k` satisfy
+    /// `P(var) = sum(b_k*B_k(var), k=0..n)` where `B_k(var) = comb(n,k)*var^k*(1-var)^(n-k)`.
+    /// An error is returned if the expression isn't a polynomial in `var`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// let b = x.to_bernstein(&x, &ctx)?;
+    /// assert_eq!(vec![0, 1], b.iter().map(|c| c.to_int()).collect::<Result<Vec<_>, _>>()?);
+    /// // x^2 = 0*(1-x)^2 + 0*2*x*(1-x) + 1*x^2
+    /// let b = Gen::from_str("x^2", &ctx)?.to_bernstein(&x, &ctx)?;
+    /// assert_eq!(vec![0, 0, 1], b.iter().map(|c| c.to_int()).collect::<Result<Vec<_>, _>>()?);
+    /// assert!(Gen::from_str("sin(x)", &ctx)?.to_bernstein(&x, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn to_bernstein(&self, var: &Gen, ctx: &Context) -> Result<Vec<Self>, GiacError> {
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_gen_bernstein(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            result.to_vec()
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Returns the norm of an algebraic number, i.e. the product of its conjugates.
+    ///
+    /// The number field is `ℚ(α)`, where `α` is a root of the irreducible univariate polynomial `minpoly`,
+    /// and `self` is written as a polynomial in the variable of `minpoly`, this variable standing for `α`.
+    /// An error is returned if `self` contains another variable, as it isn't in the field.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// // x stands for sqrt(2)
+    /// let minpoly = Gen::from_str("x^2-2", &ctx)?;
+    ///
+    /// assert_eq!(-2, Gen::from_str("x", &ctx)?.field_norm(&minpoly, &ctx)?.to_int()?);
+    /// // (1+sqrt(2))*(1-sqrt(2))
+    /// assert_eq!(-1, Gen::from_str("1+x", &ctx)?.field_norm(&minpoly, &ctx)?.to_int()?);
+    /// assert!(Gen::from_str("y", &ctx)?.field_norm(&minpoly, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn field_norm(&self, minpoly: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_field_norm(self.as_gen_ref(), minpoly.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Returns the trace of an algebraic number, i.e. the sum of its conjugates.
+    /// See [Gen::field_norm] for how the number field is defined by `minpoly`.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// // x stands for sqrt(2)
+    /// let minpoly = Gen::from_str("x^2-2", &ctx)?;
+    ///
+    /// assert_eq!(0, Gen::from_str("x", &ctx)?.field_trace(&minpoly, &ctx)?.to_int()?);
+    /// // (3+sqrt(2))+(3-sqrt(2))
+    /// assert_eq!(6, Gen::from_str("3+x", &ctx)?.field_trace(&minpoly, &ctx)?.to_int()?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn field_trace(&self, minpoly: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_field_trace(self.as_gen_ref(), minpoly.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Checks if the polynomial is monic in the variable `var`, i.e. if its leading coefficient is `1`.
+    /// For multivariate polynomials, the leading coefficient is taken in `var`, other variables being treated as coefficients.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// assert!(Gen::from_str("x^2+2*x", &ctx)?.is_monic(&x, &ctx)?);
+    /// assert!(!Gen::from_str("2*x^2+4*x", &ctx)?.is_monic(&x, &ctx)?);
+    /// assert!(!Gen::from_str("y*x^2+1", &ctx)?.is_monic(&x, &ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn is_monic(&self, var: &Gen, ctx: &Context) -> Result<bool, GiacError> {
+        let lcoeff = self.apply("lcoeff", &[var], ctx)?;
+        (lcoeff - &1.into()).is_zero(ctx)
+    }
+
+    /// Divides the polynomial by its leading coefficient in the variable `var`, making it monic.
+    /// For multivariate polynomials, the leading coefficient is taken in `var`, other variables being treated as coefficients.
+    /// The zero polynomial has no leading coefficient, so a [GiacError::NoSolution] is returned.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    ///
+    /// assert_eq!("x^2+2*x", Gen::from_str("2*x^2+4*x", &ctx)?.make_monic(&x, &ctx)?.to_string());
+    /// assert!(Gen::from(0).make_monic(&x, &ctx).is_err());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn make_monic(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        let lcoeff = self.apply("lcoeff", &[var], ctx)?;
+        if lcoeff.is_zero(ctx)? {
+            return Err(GiacError::NoSolution(
+                "the zero polynomial can't be made monic",
+            ));
+        }
+        (self.clone() / &lcoeff).apply("normal", &[], ctx)
+    }
+
+    /// Computes the Newton polygon of a polynomial in the variable `var`.
+    ///
+    /// For each non-zero coefficient `a_i` of `var^i`, the point `(i, v(a_i))` is considered,
+    /// where the valuation `v(a_i)` is the lowest total degree of the monomials of `a_i` in the other variables,
+    /// i.e. its order of vanishing at `0` (so a constant coefficient has valuation `0`).
+    /// The vertices of the lower convex hull of these points are returned as a list of `[degree, valuation]` pairs,
+    /// sorted by increasing degree.
+    ///
+    /// An error is returned for the zero polynomial.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let y = Gen::from_str("y", &ctx)?;
+    ///
+    /// let f = Gen::from_str("y^3+x*y+x^3", &ctx)?;
+    /// assert_eq!("[[0,3],[1,1],[3,0]]", f.newton_polygon(&y, &ctx)?.to_string());
+    /// // (1,2) is above the segment from (0,3) to (3,0)
+    /// let g = Gen::from_str("y^3+x^2*y+x^3", &ctx)?;
+    /// assert_eq!("[[0,3],[3,0]]", g.newton_polygon(&y, &ctx)?.to_string());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn newton_polygon(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_newton_polygon(self.as_gen_ref(), var.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Computes the Sturm sequence of a polynomial in the variable `var`, with giac's `sturm`.
+    ///
+    /// The result has giac's format: the polynomial is first split into square-free factors,
+    /// and the list contains its content followed by the Sturm sequence of each factor.
+    /// A Sturm sequence `[P0, P1, ..., Pm]` starts with the factor and its derivative, and `Pk+1` is the opposite
+    /// of the remainder of `Pk-1` divided by `Pk`, up to a positive constant. Each polynomial is written
+    /// as the list of its coefficients, by decreasing powers.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    /// use giacrs::types::GenType;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^3-x", &ctx)?;
+    ///
+    /// let sequence = p.sturm(&x, &ctx)?;
+    /// assert_eq!(GenType::Vector, sequence.get_type());
+    /// // x^3-x and its derivative 3*x^2-1
+    /// assert!(sequence.to_string().contains("[1,0,-1,0],[3,0,-1]"));
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sturm(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_sturm(self.as_gen_ref(), var.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+
+    /// Counts the distinct real roots of a polynomial in the variable `var` in the closed interval `[a, b]`,
+    /// using its Sturm sequence. Multiple roots are only counted once.
+    ///
+    /// `a` and `b` must be real numbers with `a <= b`, they can be roots of the polynomial.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^3-x", &ctx)?;
+    ///
+    /// assert_eq!(3, p.count_real_roots(&x, &(-2).into(), &2.into(), &ctx)?);
+    /// // Bounds are included
+    /// assert_eq!(2, p.count_real_roots(&x, &(-1).into(), &0.into(), &ctx)?);
+    /// assert_eq!(1, Gen::from_str("(x-1)^2", &ctx)?.count_real_roots(&x, &0.into(), &2.into(), &ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn count_real_roots(
+        &self,
+        var: &Gen,
+        a: &Gen,
+        b: &Gen,
+        ctx: &Context,
+    ) -> Result<u32, GiacError> {
+        let mut result = 0;
+        let error = unsafe {
+            ffi::giacrs_gen_count_real_roots(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                a.as_gen_ref(),
+                b.as_gen_ref(),
+                &mut result,
+                ctx.as_context_ref(),
+            )
+        };
+        if error == std::ptr::null() {
+            Ok(result)
+        } else {
+            Err(GiacError::InternalError(error.into()))
+        }
+    }
+
+    /// Isolates the real roots of a polynomial in the variable `var`, with rational coefficients.
+    ///
+    /// Returns a list of disjoint intervals `(a, b)` with rational bounds, each containing exactly one distinct real root,
+    /// which may be a multiple root. A rational root found exactly is returned as `(r, r)`.
+    /// The intervals are refined until their width is lower than the context epsilon (see [Context::set_epsilon]),
+    /// so this also gives certified approximations of the roots.
+    /// An error is returned if the expression isn't a polynomial in `var` with rational coefficients.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// let p = Gen::from_str("x^2-2", &ctx)?;
+    ///
+    /// let roots = p.isolate_roots(&x, &ctx)?;
+    /// assert_eq!(2, roots.len());
+    /// for (a, b) in &roots {
+    ///     // p changes sign in [a, b]
+    ///     assert_eq!(1, gen!("({}^2-2)*({}^2-2)<=0", a, b; ctx)?.to_int()?);
+    /// }
+    /// assert!(Gen::from_str("x^2+1", &ctx)?.isolate_roots(&x, &ctx)?.is_empty());
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn isolate_roots(&self, var: &Gen, ctx: &Context) -> Result<Vec<(Self, Self)>, GiacError> {
+        let result = Gen::new();
+        let error = unsafe {
+            ffi::giacrs_gen_isolate_roots(
+                self.as_gen_ref(),
+                var.as_gen_ref(),
+                result.as_gen_ref(),
+                ctx.as_context_ref(),
+            )
+        };
+        if error != std::ptr::null() {
+            return Err(GiacError::InternalError(error.into()));
+        }
+        result
+            .to_vec()?
+            .into_iter()
+            .map(|interval| {
+                let bounds = interval.to_vec()?;
+                Ok((bounds[0].clone(), bounds[1].clone()))
+            })
+            .collect()
+    }
+
+    /// Computes the square-free factorization of a polynomial in the variable `var`, over the rationals.
+    ///
+    /// The result is a product `P1*P2^2*...*Pk^k` where each `Pi` is square-free and the `Pi` are pairwise coprime,
+    /// so the roots of `Pi` are exactly the roots of multiplicity `i`. Factors with a constant value are omitted.
+    /// Unlike [Gen::factor], the `Pi` aren't factored further, which makes it much cheaper.
+    /// ```
+    /// use giacrs::context::Context;
+    /// use giacrs::gen::Gen;
+    ///
+    /// let ctx = Context::new();
+    /// let x = Gen::from_str("x", &ctx)?;
+    /// // (x-1)^2*(x+1)
+    /// let p = Gen::from_str("x^3-x^2-x+1", &ctx)?;
+    ///
+    /// let s = p.sqrfree(&x, &ctx)?;
+    /// assert!(s.to_string().contains("(x-1)^2"));
+    /// assert!((s - &p).simplify(&ctx)?.is_zero(&ctx)?);
+    /// # use giacrs::GiacError;
+    /// # Ok::<(), GiacError>(())
+    /// ```
+    pub fn sqrfree(&self, var: &Gen, ctx: &Context) -> Result<Self, GiacError> {
+        ffi_safe_call! {
+            ffi::giacrs_gen_sqrfree(self.as_gen_ref(), var.as_gen_ref(), result.as_gen_ref(), ctx.as_context_ref())
+        }
+    }
+}
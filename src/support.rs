@@ -1,9 +1,11 @@
 //! Internal representations to communicate with giac
 
 use std::{
+    error::Error,
     ffi::{c_char, CStr},
     fmt::{self, Debug, Display, Formatter},
     ops::Deref,
+    str::Utf8Error,
 };
 
 use crate::ffi;
@@ -15,18 +17,58 @@ pub enum GiacError {
     InternalError(GiacString),
     /// Equation don't have any solutions
     NoSolution(&'static str),
+    /// The computation was interrupted before it finished, see [Context::eval_with_timeout](crate::context::Context::eval_with_timeout)
+    Interrupted,
 }
 
+impl Display for GiacError {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+        match self {
+            GiacError::InternalError(message) => write!(f, "{}", message),
+            GiacError::NoSolution(reason) => write!(f, "No solution: {}", reason),
+            GiacError::Interrupted => write!(f, "Computation interrupted"),
+        }
+    }
+}
+
+impl Error for GiacError {}
+
 /// Represents all string created from giac. You should use this type if possible to avoid a copy, as a conversion to a rust string needs to copy the entire string.
 #[derive(Eq)]
 pub struct GiacString {
     pub(crate) ptr: *const c_char,
 }
 
+// The string is owned and never mutated, so it can be shared between threads. This allows sending a `GiacError` to `anyhow`-like errors.
+unsafe impl Send for GiacString {}
+unsafe impl Sync for GiacString {}
+
 impl GiacString {
     pub(crate) unsafe fn new(ptr: *const c_char) -> Self {
         GiacString { ptr }
     }
+
+    /// Borrows the string as a `&str` without copying it.
+    /// Returns an error if the string isn't valid UTF-8.
+    /// ```
+    /// use giacrs::gen::Gen;
+    ///
+    /// assert_eq!(Ok("42"), Gen::from(42).print_to_string().to_str());
+    /// ```
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        self.deref().to_str()
+    }
+
+    /// Copies the string to a `String`.
+    /// Unlike `to_string`, which replaces invalid UTF-8 sequences, an error is returned if the string isn't valid UTF-8.
+    /// ```
+    /// use giacrs::gen::Gen;
+    ///
+    /// assert_eq!(Ok("42".to_string()), Gen::from(42).print_to_string().to_string_checked());
+    /// ```
+    pub fn to_string_checked(&self) -> Result<String, Utf8Error> {
+        self.to_str().map(str::to_owned)
+    }
 }
 
 impl Deref for GiacString {
@@ -49,6 +91,12 @@ impl Display for GiacString {
     }
 }
 
+impl AsRef<[u8]> for GiacString {
+    fn as_ref(&self) -> &[u8] {
+        self.deref().to_bytes()
+    }
+}
+
 impl PartialEq for GiacString {
     fn eq(&self, other: &GiacString) -> bool {
         **self == **other
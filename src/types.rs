@@ -1,5 +1,7 @@
 //! Internal giac types and enums
 
+use crate::gen::Gen;
+
 /// Represents a giac expression type
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[repr(C)]
@@ -49,3 +51,69 @@ pub enum GenType {
     /// TODO what's this?
     Pointer = 20, // void * _POINTER_val
 }
+
+/// A field over which a computation is done
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Field {
+    /// The rational numbers `ℚ`, computations are exact
+    Rational,
+    /// The prime field `ℤ/pℤ`, `p` must be prime
+    Modular(u32),
+}
+
+/// Definiteness of a symmetric matrix, see [Gen::definiteness](crate::gen::Gen::definiteness)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Definiteness {
+    /// All eigenvalues are strictly positive
+    PositiveDefinite,
+    /// All eigenvalues are positive or zero
+    PositiveSemidefinite,
+    /// All eigenvalues are strictly negative
+    NegativeDefinite,
+    /// All eigenvalues are negative or zero
+    NegativeSemidefinite,
+    /// Some eigenvalues are strictly positive and some are strictly negative
+    Indefinite,
+}
+
+/// Unit used for angles in trigonometric functions, see [Context::set_angle_mode](crate::context::Context::set_angle_mode)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AngleMode {
+    /// A full turn is `2*pi`
+    Radian,
+    /// A full turn is `360`
+    Degree,
+}
+
+/// A non-zero entry `((row, column), value)` of a matrix, see [Gen::to_sparse](crate::gen::Gen::to_sparse)
+pub type SparseEntry = ((usize, usize), Gen);
+
+/// A property of a function, see [Context::assume_function](crate::context::Context::assume_function)
+#[derive(Debug, Clone)]
+pub enum FunctionProperty {
+    /// `f(-x) = f(x)`
+    Even,
+    /// `f(-x) = -f(x)`
+    Odd,
+    /// `f(x+T) = f(x)`, with the given period `T`
+    Periodic(Gen),
+}
+
+/// Typed view of the top-level node of an expression, see [Gen::classify](crate::gen::Gen::classify)
+#[derive(Debug, Clone)]
+pub enum Node {
+    /// A sum, with its terms. Giac stores `a-b` as `a+(-b)`
+    Add(Vec<Gen>),
+    /// A product, with its factors. Giac stores `a/b` as `a*inv(b)`
+    Mul(Vec<Gen>),
+    /// A power, with its base and exponent
+    Pow(Gen, Gen),
+    /// Any other operator or function applied to its arguments, like `sin`, `-` (negation) or `inv`
+    Call(String, Vec<Gen>),
+    /// An identifier, like `x` or `pi`
+    Symbol(String),
+    /// A number: integer, float, fraction or complex number
+    Number(Gen),
+    /// Any other expression, like vectors, strings or modular numbers
+    Other(Gen),
+}
@@ -15,3 +15,113 @@ extern "C" const giac::context *giacrs_new_context() {
 extern "C" void giacrs_free_context(const giac::context *ctx) { delete ctx; }
 
 extern "C" void giacrs_release_globals() { giac::release_globals(); }
+
+extern "C" result giacrs_context_eval_gen(giac::gen *e,
+                                          const giac::context *ctx,
+                                          giac::gen *res) {
+    SAFE_CALL(giac::eval(*e, ctx));
+}
+
+// Parses a variable name, which must be a valid identifier
+static giac::gen parse_identifier(const char *name, const giac::context *ctx) {
+    giac::gen id(std::string(name), ctx);
+    if (giac::first_error_line(ctx) != 0 || id.type != giac::_IDNT) {
+        throw std::runtime_error("Invalid variable name");
+    }
+    return id;
+}
+
+// Reads the value stored for an identifier, without evaluating it, so that
+// assumptions are kept. Returns false if the identifier is unassigned.
+static bool stored_value(const giac::gen &id, const giac::context *ctx,
+                         giac::gen &value) {
+    // Variables of the global context are stored in the identifier
+    if (!ctx || !ctx->tabptr) {
+        if (!id._IDNTptr->value) {
+            return false;
+        }
+        value = *id._IDNTptr->value;
+        return true;
+    }
+    auto it = ctx->tabptr->find(id._IDNTptr->id_name);
+    if (it == ctx->tabptr->end()) {
+        return false;
+    }
+    value = it->second;
+    return true;
+}
+
+extern "C" result giacrs_context_eval_with(const char *s, const char **names,
+                                           giac::gen **values, size_t n,
+                                           const giac::context *ctx,
+                                           giac::gen *res) {
+    SAFE_VOID_CALL({
+        giac::gen expr(s, ctx);
+        if (giac::first_error_line(ctx) != 0) {
+            return string_to_c(giac::parser_error(ctx));
+        }
+        giac::vecteur vars(n);
+        for (size_t i = 0; i < n; i++) {
+            vars[i] = parse_identifier(names[i], ctx);
+        }
+        // Previous stored values, including assumptions
+        giac::vecteur saved;
+        std::vector<bool> assigned;
+        auto restore = [&]() {
+            for (size_t i = saved.size(); i-- > 0;) {
+                if (assigned[i]) {
+                    giac::sto(saved[i], vars[i], ctx);
+                } else {
+                    giac::_purge(vars[i], ctx);
+                }
+            }
+        };
+        try {
+            for (size_t i = 0; i < n; i++) {
+                giac::gen value;
+                assigned.push_back(stored_value(vars[i], ctx, value));
+                saved.push_back(value);
+                giac::sto(*values[i], vars[i], ctx);
+            }
+            *res = giac::eval(expr, ctx);
+        } catch (...) {
+            restore();
+            throw;
+        }
+        restore();
+    });
+}
+
+extern "C" result giacrs_context_assign(const char *name, giac::gen *value,
+                                        const giac::context *ctx) {
+    SAFE_VOID_CALL({ giac::sto(*value, parse_identifier(name, ctx), ctx); });
+}
+
+extern "C" result giacrs_context_get(const char *name, const giac::context *ctx,
+                                     giac::gen *res) {
+    SAFE_CALL(giac::eval(parse_identifier(name, ctx), 1, ctx));
+}
+
+extern "C" result giacrs_context_purge(const char *name,
+                                       const giac::context *ctx) {
+    SAFE_VOID_CALL({ giac::_purge(parse_identifier(name, ctx), ctx); });
+}
+
+extern "C" result giacrs_context_check_identifier(const char *name,
+                                                  const giac::context *ctx) {
+    SAFE_VOID_CALL({ parse_identifier(name, ctx); });
+}
+
+extern "C" result giacrs_context_variables(const giac::context *ctx,
+                                           giac::gen *res) {
+    SAFE_CALL(giac::_VARS(0, ctx));
+}
+
+extern "C" void giacrs_interrupt() { giac::ctrl_c = true; }
+
+extern "C" bool giacrs_is_interrupted() { return giac::interrupted; }
+
+extern "C" void giacrs_clear_interrupt() {
+    giac::ctrl_c = false;
+    giac::interrupted = false;
+}
@@ -1,5 +1,7 @@
 #include "gen.hpp"
 #include "utils.hpp"
+#include <algorithm>
+#include <cmath>
 #include <cstdint>
 #include <cstring>
 #include <giac/config.h>
@@ -17,6 +19,7 @@
 #include <giac/usual.h>
 #include <giac/vecteur.h>
 #include <stdexcept>
+#include <vector>
 
 char *string_to_c(std::string s) {
     char *str = (char *)malloc((s.length() + 1) * sizeof(char));
@@ -38,6 +41,17 @@ extern "C" result giacrs_gen_from_str(char *s, const giac::context *ctx,
 }
 
 extern "C" giac::gen *giacrs_gen_from_int(int i) { return new giac::gen(i); }
+extern "C" giac::gen *giacrs_gen_from_longlong(long long i) {
+    return new giac::gen(i);
+}
+extern "C" giac::gen *giacrs_gen_from_ulonglong(unsigned long long i) {
+    if (i <= (unsigned long long)INT64_MAX) {
+        return new giac::gen((long long)i);
+    }
+    // Out of the long long range, built from its two 32 bits halves
+    giac::gen high((long long)(i >> 32)), low((long long)(i & 0xffffffff));
+    return new giac::gen(high * giac::gen(4294967296LL) + low);
+}
 extern "C" giac::gen *giacrs_gen_from_float(float i) {
     return new giac::gen(i);
 }
@@ -48,6 +62,18 @@ extern "C" giac::gen *giacrs_gen_from_double(double i) {
 extern "C" giac::gen *giacrs_gen_factorial(unsigned long i) {
     return new giac::gen(giac::factorial(i));
 }
+extern "C" giac::gen *giacrs_gen_pi(const giac::context *ctx) {
+    return new giac::gen(giac::cst_pi);
+}
+extern "C" giac::gen *giacrs_gen_e(const giac::context *ctx) {
+    return new giac::gen(giac::exp(1, ctx));
+}
+extern "C" giac::gen *giacrs_gen_i(const giac::context *ctx) {
+    return new giac::gen(giac::cst_i);
+}
+extern "C" giac::gen *giacrs_gen_infinity(const giac::context *ctx) {
+    return new giac::gen(giac::plus_inf);
+}
 
 extern "C" void giacrs_free_gen(giac::gen *e) { delete e; }
 
@@ -63,16 +89,167 @@ extern "C" result giacrs_gen_is_zero(giac::gen *e, bool *res,
     SAFE_CALL(giac::is_zero(*e, ctx));
 }
 
+extern "C" result giacrs_gen_is_undef(giac::gen *e, bool *res,
+                                      giac::context *ctx) {
+    SAFE_CALL(giac::is_undef(*e));
+}
+
+extern "C" result giacrs_gen_is_infinite(giac::gen *e, bool *res,
+                                         giac::context *ctx) {
+    SAFE_CALL(giac::is_inf(*e));
+}
+
+// Returns the operator name of a symbolic expression, or an empty string
+static std::string operator_name(const giac::gen &e) {
+    return e.type == giac::_SYMB ? e._SYMBptr->sommet.ptr()->s : "";
+}
+
+// Returns the operands of a symbolic expression, or an empty vector
+static giac::vecteur operands(const giac::gen &e) {
+    if (e.type != giac::_SYMB) {
+        return giac::vecteur(0);
+    }
+    const giac::gen &args = e._SYMBptr->feuille;
+    // Several operands are stored as a sequence
+    if (args.type == giac::_VECT && args.subtype == giac::_SEQ__VECT) {
+        return *args._VECTptr;
+    }
+    return giac::vecteur(1, args);
+}
+
+extern "C" const char *giacrs_gen_operator_name(giac::gen *e) {
+    if (e->type != giac::_SYMB) {
+        return NULL;
+    }
+    return string_to_c(operator_name(*e));
+}
+
+extern "C" result giacrs_gen_operands(giac::gen *e, giac::gen *res) {
+    SAFE_CALL(giac::gen(operands(*e)));
+}
+
+extern "C" result giacrs_gen_vector_size(giac::gen *e, size_t *res) {
+    SAFE_VOID_CALL({
+        if (e->type != giac::_VECT) {
+            throw std::runtime_error("Expression is not a vector");
+        }
+        *res = e->_VECTptr->size();
+    });
+}
+
+extern "C" result giacrs_gen_vector_at(giac::gen *e, size_t i,
+                                       giac::gen *res) {
+    SAFE_VOID_CALL({
+        if (e->type != giac::_VECT || i >= e->_VECTptr->size()) {
+            throw std::runtime_error("Index out of range");
+        }
+        *res = (*e->_VECTptr)[i];
+    });
+}
+
+// Estimated size in bytes of a gen and of the objects it points to
+static size_t memory_size(const giac::gen &e) {
+    size_t size = sizeof(giac::gen);
+    switch (e.type) {
+    case giac::_ZINT:
+        size += sizeof(mpz_t) + mpz_size(*e._ZINTptr) * sizeof(mp_limb_t);
+        break;
+    case giac::_REAL:
+        size += sizeof(giac::real_object);
+#ifdef HAVE_LIBMPFR
+        size += mpfr_custom_get_size(mpfr_get_prec(e._REALptr->inf));
+#else
+        size += (mpf_get_prec(e._REALptr->inf) / mp_bits_per_limb + 2) *
+                sizeof(mp_limb_t);
+#endif
+        break;
+    case giac::_CPLX:
+        size += memory_size(e._CPLXptr[0]) + memory_size(e._CPLXptr[1]);
+        break;
+    // Value and modulus
+    case giac::_MOD:
+        size += memory_size(e._MODptr[0]) + memory_size(e._MODptr[1]);
+        break;
+    // Value and minimal polynomial
+    case giac::_EXT:
+        size += memory_size(e._EXTptr[0]) + memory_size(e._EXTptr[1]);
+        break;
+    case giac::_MAP:
+        size += sizeof(giac::gen_map);
+        for (const auto &entry : *e._MAPptr) {
+            size += memory_size(entry.first) + memory_size(entry.second);
+        }
+        break;
+    case giac::_FRAC:
+        size += memory_size(e._FRACptr->num) + memory_size(e._FRACptr->den);
+        break;
+    case giac::_VECT:
+        size += sizeof(giac::vecteur);
+        for (const giac::gen &x : *e._VECTptr) {
+            size += memory_size(x);
+        }
+        break;
+    case giac::_SYMB:
+        size += sizeof(giac::symbolic) + memory_size(e._SYMBptr->feuille);
+        break;
+    case giac::_IDNT:
+        size += sizeof(giac::identificateur) + strlen(e._IDNTptr->id_name);
+        break;
+    case giac::_STRNG:
+        size += sizeof(std::string) + e._STRNGptr->size();
+        break;
+    case giac::_POLY:
+        size += sizeof(giac::polynome);
+        for (const giac::monomial<giac::gen> &m : e._POLYptr->coord) {
+            size += sizeof(giac::monomial<giac::gen>) - sizeof(giac::gen) +
+                    memory_size(m.value) + m.index.size() * sizeof(short);
+        }
+        break;
+    default:
+        break;
+    }
+    return size;
+}
+
+extern "C" size_t giacrs_gen_memory_size(giac::gen *e) {
+    return memory_size(*e);
+}
+
 // CONVERSION
 
 extern "C" const char *giacrs_gen_to_str(giac::gen *e) {
     return string_to_c(e->print());
 }
 
+extern "C" result giacrs_gen_print(giac::gen *e, const char **res,
+                                   giac::context *ctx) {
+    SAFE_CALL(string_to_c(e->print(ctx)));
+}
+
+extern "C" result giacrs_gen_latex(giac::gen *e, const char **res,
+                                   giac::context *ctx) {
+    SAFE_CALL(string_to_c(giac::gen2tex(*e, ctx)));
+}
+
+extern "C" result giacrs_gen_mathml(giac::gen *e, const char **res,
+                                    giac::context *ctx) {
+    SAFE_CALL(string_to_c(
+        "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">" +
+        giac::gen2mathml(*e, ctx) + "</math>"));
+}
+
 extern "C" result giacrs_gen_to_int(giac::gen *e, int *res) {
     SAFE_CALL(e->to_int());
 }
 
+extern "C" result giacrs_gen_to_double(giac::gen *e, double *res) {
+    SAFE_VOID_CALL({
+        if (e->type != giac::_DOUBLE_)
+            throw std::runtime_error("Expression is not a double");
+        *res = e->_DOUBLE_val;
+    });
+}
+
 // METHODS
 
 extern "C" result giacrs_gen_gcd(giac::gen *a, giac::gen *b, giac::gen *res,
@@ -89,6 +266,16 @@ extern "C" result giacrs_gen_ifactor(giac::gen *e, giac::gen *res,
     SAFE_CALL(giac::_ifactor(*e, ctx));
 }
 
+extern "C" result giacrs_gen_ifactor_batch(giac::gen **values,
+                                           giac::gen **res, size_t n,
+                                           const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        for (size_t i = 0; i < n; i++) {
+            *res[i] = giac::_ifactor(*values[i], ctx);
+        }
+    });
+}
+
 extern "C" result giacrs_gen_ifactors(giac::gen *e, giac::gen *res,
                                       const giac::context *ctx) {
     SAFE_CALL(giac::_ifactors(*e, ctx));
@@ -130,6 +317,11 @@ extern "C" result giacrs_gen_is_pseudoprime(giac::gen *a, int8_t *res) {
     SAFE_CALL(giac::is_probab_prime_p(*a));
 }
 
+extern "C" result giacrs_gen_is_prime(giac::gen *a, bool *res,
+                                      giac::context *ctx) {
+    SAFE_CALL(!giac::is_zero(giac::_isprime(*a, ctx)));
+}
+
 extern "C" result giacrs_gen_nextprime(giac::gen *a, giac::gen *res) {
     SAFE_CALL(giac::nextprime(*a));
 }
@@ -155,11 +347,50 @@ extern "C" result giacrs_gen_nthprime(giac::gen *a, giac::gen *res,
     }
 }
 
+// Calls a giac command by name, for the commands without a C++ declaration
+static giac::gen call_command(const char *name, const giac::gen &args,
+                              const giac::context *ctx) {
+    giac::gen f(std::string(name), ctx);
+    if (f.type != giac::_FUNC) {
+        throw std::runtime_error(std::string("Unknown giac command ") + name);
+    }
+    return f(args, ctx);
+}
+
+extern "C" result giacrs_gen_prime_pi(giac::gen *a, giac::gen *res,
+                                      giac::context *ctx) {
+    SAFE_CALL(giac::_nprimes(*a, ctx));
+}
+
+// Converts an unsigned index to a giac integer
+static giac::gen index_to_gen(unsigned long n) {
+    if (n > (unsigned long)INT64_MAX) {
+        throw std::runtime_error("Index is too large");
+    }
+    return giac::gen((long long)n);
+}
+
+extern "C" result giacrs_gen_fibonacci(unsigned long n, giac::gen *res,
+                                       giac::context *ctx) {
+    SAFE_CALL(call_command("fibonacci", index_to_gen(n), ctx));
+}
+
+extern "C" result giacrs_gen_lucas(unsigned long n, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(call_command("lucas", index_to_gen(n), ctx));
+}
+
 extern "C" result giacrs_gen_iegcd(giac::gen *a, giac::gen *b, giac::gen *u,
                                    giac::gen *v, giac::gen *d) {
     SAFE_VOID_CALL(giac::egcd(*a, *b, *u, *v, *d););
 }
 
+extern "C" result giacrs_gen_powmod(giac::gen *a, giac::gen *exp,
+                                    giac::gen *modulus, giac::gen *res,
+                                    giac::context *ctx) {
+    SAFE_CALL(giac::_powmod(giac::makesequence(*a, *exp, *modulus), ctx));
+}
+
 extern "C" result giacrs_gen_iabcuv(giac::gen *a, giac::gen *b, giac::gen *c,
                                     giac::gen *u, giac::gen *v,
                                     giac::context *ctx) {
@@ -190,6 +421,62 @@ extern "C" result giacrs_gen_euler(giac::gen *a, giac::gen *res,
     SAFE_CALL(giac::euler(*a, ctx));
 }
 
+// Returns the prime factorization of a positive integer as [p1,e1,p2,e2,...]
+static giac::vecteur positive_ifactors(const giac::gen &n,
+                                       const giac::context *ctx) {
+    if (!n.is_integer() || giac::is_strictly_greater(1, n, ctx)) {
+        throw std::runtime_error("Argument must be a positive integer");
+    }
+    giac::gen factors = giac::_ifactors(n, ctx);
+    if (factors.type != giac::_VECT) {
+        throw std::runtime_error("Failed to factorize");
+    }
+    return *factors._VECTptr;
+}
+
+extern "C" result giacrs_gen_moebius(giac::gen *a, giac::gen *res,
+                                     giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur factors = positive_ifactors(*a, ctx);
+        int mu = 1;
+        for (size_t i = 0; i + 1 < factors.size(); i += 2) {
+            if (!giac::is_one(factors[i + 1])) {
+                mu = 0;
+                break;
+            }
+            mu = -mu;
+        }
+        *res = giac::gen(mu);
+    });
+}
+
+extern "C" result giacrs_gen_sigma(giac::gen *a, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur factors = positive_ifactors(*a, ctx);
+        giac::gen sigma(1);
+        for (size_t i = 0; i + 1 < factors.size(); i += 2) {
+            // (p^(e+1)-1)/(p-1)
+            const giac::gen &p = factors[i];
+            sigma = sigma * giac::iquo(giac::pow(p, factors[i + 1] + 1, ctx) - 1,
+                                       p - 1);
+        }
+        *res = sigma;
+    });
+}
+
+extern "C" result giacrs_gen_tau(giac::gen *a, giac::gen *res,
+                                 giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur factors = positive_ifactors(*a, ctx);
+        giac::gen tau(1);
+        for (size_t i = 0; i + 1 < factors.size(); i += 2) {
+            tau = tau * (factors[i + 1] + 1);
+        }
+        *res = tau;
+    });
+}
+
 extern "C" result giacrs_gen_legendre(giac::gen *a, giac::gen *b, int8_t *res) {
     SAFE_CALL(giac::legendre(*a, *b));
 }
@@ -216,39 +503,1644 @@ extern "C" result giacrs_gen_rand(giac::gen *n, giac::gen *res,
     SAFE_CALL(giac::_rand(*n, ctx));
 }
 
+extern "C" result giacrs_gen_rand_range(giac::gen *lower, giac::gen *upper,
+                                        giac::gen *res,
+                                        const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_integer(*lower) || !giac::is_integer(*upper)) {
+            throw std::runtime_error("Bounds must be integers");
+        }
+        if (!giac::is_strictly_greater(*upper, *lower, ctx)) {
+            throw std::runtime_error(
+                "Lower bound must be smaller than upper bound");
+        }
+        *res = *lower + giac::_rand(*upper - *lower, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_random_matrix(size_t rows, size_t cols,
+                                           giac::gen *res,
+                                           const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (rows == 0 || cols == 0) {
+            throw std::runtime_error("Matrix size must be positive");
+        }
+        *res = giac::_ranm(
+            giac::makesequence(giac::gen(int(rows)), giac::gen(int(cols))),
+            ctx);
+    });
+}
+
+extern "C" result giacrs_gen_randbinomial(giac::gen *n, giac::gen *p,
+                                          giac::gen *res,
+                                          const giac::context *ctx) {
+    SAFE_CALL(giac::_randbinomial(giac::makesequence(*n, *p), ctx));
+}
+
+extern "C" result giacrs_gen_randnormal(giac::gen *mean, giac::gen *stddev,
+                                        giac::gen *res,
+                                        const giac::context *ctx) {
+    SAFE_CALL(giac::_randNorm(giac::makesequence(*mean, *stddev), ctx));
+}
+
+extern "C" result giacrs_gen_evalf(giac::gen *e, unsigned int digits,
+                                   giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_evalf(giac::makesequence(*e, giac::gen(int(digits))),
+                           ctx));
+}
+
+extern "C" result giacrs_gen_evalf_context(giac::gen *e, giac::gen *res,
+                                           giac::context *ctx) {
+    SAFE_CALL(giac::_evalf(*e, ctx));
+}
+
+extern "C" result giacrs_gen_evalf_reliable(giac::gen *e,
+                                            unsigned int target_digits,
+                                            unsigned int max_digits,
+                                            giac::gen *res,
+                                            giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (target_digits >= max_digits) {
+            throw std::runtime_error("Target precision exceeds the cap");
+        }
+        // Checked before adding, to avoid any overflow
+        unsigned int digits = max_digits - target_digits > 10
+                                  ? target_digits + 10
+                                  : max_digits;
+        giac::gen previous = giac::_evalf(
+            giac::makesequence(*e, giac::gen(int(digits))), ctx);
+        if (!giac::is_fully_numeric(previous)) {
+            throw std::runtime_error("Expression is not numeric");
+        }
+        giac::gen tolerance =
+            giac::pow(giac::gen(10), -giac::gen(int(target_digits)), ctx);
+        // Double the precision until two successive values agree
+        while (true) {
+            if (digits > max_digits / 2) {
+                throw std::runtime_error(
+                    "Failed to reach a stable value within the precision cap");
+            }
+            digits *= 2;
+            giac::gen current = giac::_evalf(
+                giac::makesequence(*e, giac::gen(int(digits))), ctx);
+            giac::gen difference = giac::abs(current - previous, ctx);
+            if (giac::is_zero(difference, ctx) ||
+                giac::is_greater(tolerance * giac::abs(current, ctx),
+                                 difference, ctx)) {
+                *res = giac::_evalf(
+                    giac::makesequence(current, giac::gen(int(target_digits))),
+                    ctx);
+                break;
+            }
+            previous = current;
+        }
+    });
+}
+
+// Computes round(e*10^scale), checking it fits in the 96 bits mantissa of a
+// rust_decimal Decimal
+extern "C" result giacrs_gen_decimal_mantissa(giac::gen *e, unsigned int scale,
+                                              giac::gen *res,
+                                              giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (scale > 28) {
+            throw std::runtime_error("scale must be at most 28");
+        }
+        giac::gen m = giac::_round(
+            *e * giac::pow(giac::gen(10), giac::gen(int(scale)), ctx), ctx);
+        if (!giac::is_integer(m)) {
+            throw std::runtime_error("Expression is not a real number");
+        }
+        giac::gen max = giac::pow(giac::gen(2), giac::gen(96), ctx);
+        if (giac::is_greater(giac::abs(m, ctx), max, ctx)) {
+            throw std::runtime_error("Value exceeds the range of a Decimal");
+        }
+        *res = m;
+    });
+}
+
 extern "C" result giacrs_gen_float2rational(giac::gen *n, giac::gen *res,
                                             giac::context *ctx) {
     SAFE_CALL(giac::_float2rational(*n, ctx));
 }
 
-// OPERATOR
+extern "C" result giacrs_gen_exact(giac::gen *e, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(giac::_exact(*e, ctx));
+}
 
-extern "C" result giacrs_gen_add(giac::gen *res, giac::gen *f) {
-    SAFE_CALL(*res + *f)
+extern "C" result giacrs_gen_rational_reconstruct(giac::gen *n,
+                                                  giac::gen *max_denom,
+                                                  giac::gen *res,
+                                                  giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (giac::is_strictly_greater(1, *max_denom, ctx)) {
+            throw std::runtime_error("max_denom must be at least 1");
+        }
+        giac::gen target = giac::_exact(*n, ctx), x = target;
+        // Convergents p/q of the continued fraction, starting from
+        // p(-2)/q(-2) = 0/1 and p(-1)/q(-1) = 1/0
+        giac::gen p0(0), q0(1), p1(1), q1(0);
+        while (true) {
+            giac::gen a = giac::_floor(x, ctx);
+            giac::gen p2 = a * p1 + p0, q2 = a * q1 + q0;
+            if (giac::is_strictly_greater(q2, *max_denom, ctx)) {
+                break;
+            }
+            p0 = p1;
+            q0 = q1;
+            p1 = p2;
+            q1 = q2;
+            giac::gen frac = x - a;
+            if (giac::is_zero(frac, ctx)) {
+                break;
+            }
+            x = giac::inv(frac, ctx);
+        }
+        *res = p1 / q1;
+        // The semiconvergent (k*p1+p0)/(k*q1+q0) with the largest k allowed
+        // by the bound may be closer than the last convergent
+        giac::gen k = giac::_floor((*max_denom - q0) / q1, ctx);
+        if (giac::is_strictly_positive(k, ctx)) {
+            giac::gen semi = (k * p1 + p0) / (k * q1 + q0);
+            if (giac::is_strictly_greater(giac::abs(*res - target, ctx),
+                                          giac::abs(semi - target, ctx),
+                                          ctx)) {
+                *res = semi;
+            }
+        }
+    });
 }
-extern "C" result giacrs_gen_sub(giac::gen *res, giac::gen *f) {
-    SAFE_CALL(*res - *f)
+
+extern "C" result giacrs_gen_numer(giac::gen *e, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(giac::_numer(*e, ctx));
 }
-extern "C" result giacrs_gen_mul(giac::gen *res, giac::gen *f) {
-    SAFE_CALL(*res * *f)
+
+extern "C" result giacrs_gen_denom(giac::gen *e, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(giac::_denom(*e, ctx));
 }
-extern "C" result giacrs_gen_div(giac::gen *res, giac::gen *f) {
-    SAFE_CALL(*res / *f)
+
+extern "C" result giacrs_gen_to_mixed_number(giac::gen *e,
+                                             giac::gen *integer_part,
+                                             giac::gen *numerator,
+                                             giac::gen *denominator,
+                                             giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen n, d;
+        giac::fxnd(*e, n, d);
+        if (!giac::is_integer(n) || !giac::is_integer(d)) {
+            throw std::runtime_error("Expression is not a rational number");
+        }
+        if (giac::is_strictly_positive(-d, ctx)) {
+            n = -n;
+            d = -d;
+        }
+        // Divide the absolute value to get the same sign on both parts
+        giac::gen a = giac::abs(n, ctx);
+        giac::gen q = giac::iquo(a, d);
+        giac::gen r = a - q * d;
+        if (giac::is_strictly_positive(-n, ctx)) {
+            q = -q;
+            r = -r;
+        }
+        *integer_part = q;
+        *numerator = r;
+        *denominator = d;
+    });
 }
 
-// OTHERS
+extern "C" result giacrs_gen_linsolve(giac::gen *a, giac::gen *b,
+                                      giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_linsolve(giac::makesequence(*a, *b), ctx));
+}
 
-extern "C" result giacrs_gen_factor(giac::gen *e, giac::gen *res,
-                                    const giac::context *ctx) {
-    SAFE_CALL(giac::_factor(*e, ctx));
+extern "C" result giacrs_gen_rsolve(giac::gen *recurrence, giac::gen *func,
+                                    giac::gen *var, giac::gen *res,
+                                    giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen unknown = giac::symb_of(*func, *var);
+        giac::vecteur args;
+        if (recurrence->type == giac::_VECT &&
+            !recurrence->_VECTptr->empty()) {
+            // [equation, initial conditions...]
+            const giac::vecteur &v = *recurrence->_VECTptr;
+            args.push_back(v.front());
+            args.push_back(unknown);
+            args.insert(args.end(), v.begin() + 1, v.end());
+        } else {
+            args.push_back(*recurrence);
+            args.push_back(unknown);
+        }
+        *res = giac::_rsolve(giac::gen(args, giac::_SEQ__VECT), ctx);
+    });
 }
 
-extern "C" result giacrs_gen_simplify(giac::gen *e, giac::gen *res,
-                                      const giac::context *ctx) {
-    SAFE_CALL(giac::simplify(*e, ctx));
+extern "C" result giacrs_gen_nsolve_system(giac::gen *equations,
+                                           giac::gen *vars, giac::gen *guess,
+                                           giac::gen *res,
+                                           giac::context *ctx) {
+    SAFE_VOID_CALL({
+        *res = giac::_fsolve(giac::makesequence(*equations, *vars, *guess),
+                             ctx);
+        // fsolve returns undef or an empty list when it doesn't converge
+        if (giac::is_undef(*res)) {
+            *res = giac::gen(giac::vecteur(0));
+        }
+    });
 }
 
-extern "C" result giacrs_gen_det(giac::gen *e, giac::gen *res,
-                                 const giac::context *ctx) {
-    SAFE_CALL(giac::_det(*e, ctx));
+extern "C" result giacrs_gen_poly_compose_mod(giac::gen *f, giac::gen *g,
+                                              giac::gen *h, giac::gen *var,
+                                              giac::gen *res,
+                                              giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*f, *var), ctx);
+        if (coeffs.type != giac::_VECT) {
+            coeffs = giac::gen(giac::vecteur(1, coeffs));
+        }
+        giac::gen g_mod = giac::_rem(giac::makesequence(*g, *h, *var), ctx);
+        // Horner scheme, reducing modulo h at each step
+        giac::gen acc(0);
+        for (const giac::gen &c : *coeffs._VECTptr) {
+            acc = giac::_rem(giac::makesequence(acc * g_mod + c, *h, *var), ctx);
+        }
+        *res = acc;
+    });
+}
+
+extern "C" result giacrs_gen_shift_poly_coeffs(giac::gen *p, giac::gen *var,
+                                               giac::gen *x0, giac::gen *res,
+                                               giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen shifted = giac::subst(*p, *var, *var + *x0, false, ctx);
+        giac::gen coeffs =
+            giac::_symb2poly(giac::makesequence(shifted, *var), ctx);
+        giac::vecteur v = coeffs.type == giac::_VECT ? *coeffs._VECTptr
+                                                     : giac::vecteur(1, coeffs);
+        // symb2poly gives coefficients from highest to lowest degree
+        std::reverse(v.begin(), v.end());
+        *res = giac::gen(v);
+    });
+}
+
+extern "C" result giacrs_gen_proot(giac::gen *e, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(giac::_proot(*e, ctx));
+}
+
+extern "C" result giacrs_gen_degree(giac::gen *e, giac::gen *var, int *res,
+                                    giac::context *ctx) {
+    SAFE_CALL(giac::_degree(giac::makesequence(*e, *var), ctx).to_int());
+}
+
+extern "C" result giacrs_gen_coeff(giac::gen *e, giac::gen *var,
+                                   unsigned int n, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_CALL(giac::_coeff(giac::makesequence(*e, *var, giac::gen(int(n))),
+                           ctx));
+}
+
+extern "C" result giacrs_gen_coeffs(giac::gen *e, giac::gen *var,
+                                    giac::gen *res, giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*e, *var), ctx);
+        // Return a plain vector instead of a poly1 one
+        *res = giac::gen(coeffs.type == giac::_VECT ? *coeffs._VECTptr
+                                                    : giac::vecteur(1, coeffs));
+    });
+}
+
+extern "C" result giacrs_gen_numeric_coeffs(giac::gen *e, giac::gen *var,
+                                            giac::gen *res,
+                                            giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*e, *var), ctx);
+        giac::vecteur v = coeffs.type == giac::_VECT ? *coeffs._VECTptr
+                                                     : giac::vecteur(1, coeffs);
+        giac::vecteur pairs;
+        int degree = int(v.size()) - 1;
+        for (const giac::gen &c : v) {
+            if (!giac::is_zero(c)) {
+                giac::gen d = giac::evalf_double(c, 1, ctx);
+                if (d.type != giac::_DOUBLE_)
+                    throw std::runtime_error(
+                        "Coefficient of degree " + std::to_string(degree) +
+                        " is not numeric");
+                pairs.push_back(giac::makevecteur(degree, d));
+            }
+            degree--;
+        }
+        *res = giac::gen(pairs);
+    });
+}
+
+extern "C" result giacrs_gen_bernstein(giac::gen *e, giac::gen *var,
+                                       giac::gen *res, giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*e, *var), ctx);
+        giac::vecteur v = coeffs.type == giac::_VECT ? *coeffs._VECTptr
+                                                     : giac::vecteur(1, coeffs);
+        for (const giac::gen &c : v) {
+            if (giac::contains(c, *var)) {
+                throw std::runtime_error("Expression is not a polynomial");
+            }
+        }
+        // Coefficients a_i of var^i, lowest degree first
+        std::reverse(v.begin(), v.end());
+        int n = int(v.size()) - 1;
+        // b_k = sum(comb(k,i)/comb(n,i)*a_i, i=0..k)
+        giac::vecteur b;
+        for (int k = 0; k <= n; k++) {
+            giac::gen sum(0);
+            for (int i = 0; i <= k; i++) {
+                giac::gen ratio = giac::_comb(giac::makesequence(k, i), ctx) /
+                                  giac::_comb(giac::makesequence(n, i), ctx);
+                sum = sum + ratio * v[i];
+            }
+            b.push_back(giac::_normal(sum, ctx));
+        }
+        *res = b;
+    });
+}
+
+// Lowest total degree of the monomials of a non-zero polynomial
+static long long total_valuation(const giac::gen &a, giac::context *ctx) {
+    giac::vecteur vars = giac::lidnt(a);
+    if (vars.empty()) {
+        return 0;
+    }
+    // Substitute each variable v by t*v, so the total degree becomes the
+    // degree in t
+    giac::gen t(giac::identificateur("giacrs_valuation_t"));
+    giac::vecteur scaled(vars.size());
+    for (size_t i = 0; i < vars.size(); i++) {
+        scaled[i] = t * vars[i];
+    }
+    giac::gen scaled_a = giac::subst(a, vars, scaled, false, ctx);
+    return giac::_valuation(giac::makesequence(scaled_a, t), ctx).val;
+}
+
+extern "C" result giacrs_gen_newton_polygon(giac::gen *e, giac::gen *var,
+                                            giac::gen *res,
+                                            giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*e, *var), ctx);
+        giac::vecteur v = coeffs.type == giac::_VECT ? *coeffs._VECTptr
+                                                     : giac::vecteur(1, coeffs);
+        // Points (degree, valuation), by increasing degree
+        std::vector<std::pair<long long, long long>> points;
+        for (size_t i = v.size(); i-- > 0;) {
+            if (!giac::is_zero(v[i])) {
+                points.push_back({(long long)(v.size() - 1 - i),
+                                  total_valuation(v[i], ctx)});
+            }
+        }
+        if (points.empty()) {
+            throw std::runtime_error(
+                "The zero polynomial has no Newton polygon");
+        }
+        // Lower convex hull with Andrew's monotone chain, keeping only the
+        // vertices
+        std::vector<std::pair<long long, long long>> hull;
+        for (const auto &p : points) {
+            while (hull.size() >= 2) {
+                const auto &a = hull[hull.size() - 2];
+                const auto &b = hull[hull.size() - 1];
+                long long cross = (b.first - a.first) * (p.second - a.second) -
+                                  (b.second - a.second) * (p.first - a.first);
+                if (cross > 0) {
+                    break;
+                }
+                hull.pop_back();
+            }
+            hull.push_back(p);
+        }
+        giac::vecteur vertices;
+        for (const auto &p : hull) {
+            vertices.push_back(giac::makevecteur(giac::gen(p.first),
+                                                 giac::gen(p.second)));
+        }
+        *res = giac::gen(vertices);
+    });
+}
+
+extern "C" result giacrs_gen_sturm(giac::gen *e, giac::gen *var,
+                                   giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_sturm(giac::makesequence(*e, *var), ctx));
+}
+
+extern "C" result giacrs_gen_count_real_roots(giac::gen *e, giac::gen *var,
+                                              giac::gen *a, giac::gen *b,
+                                              unsigned int *res,
+                                              giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (giac::is_strictly_greater(*a, *b, ctx)) {
+            throw std::runtime_error("a must be lower than b");
+        }
+        // sturmab counts the roots in ]a,b]
+        int count =
+            giac::_sturmab(giac::makesequence(*e, *var, *a, *b), ctx).val;
+        if (giac::is_zero(giac::subst(*e, *var, *a, false, ctx), ctx)) {
+            count++;
+        }
+        *res = count;
+    });
+}
+
+extern "C" result giacrs_gen_isolate_roots(giac::gen *e, giac::gen *var,
+                                           giac::gen *res,
+                                           giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen coeffs = giac::_symb2poly(giac::makesequence(*e, *var), ctx);
+        if (coeffs.type != giac::_VECT) {
+            coeffs = giac::gen(giac::vecteur(1, coeffs));
+        }
+        for (const giac::gen &c : *coeffs._VECTptr) {
+            if (!giac::is_integer(c) && c.type != giac::_FRAC) {
+                throw std::runtime_error(
+                    "Polynomial must have rational coefficients");
+            }
+        }
+        // var is the only variable of e, realroot returns a list of
+        // [interval, multiplicity], where the interval is an exact root if it
+        // was found
+        giac::gen roots =
+            giac::_realroot(giac::makesequence(*e, giac::epsilon(ctx)), ctx);
+        if (roots.type != giac::_VECT) {
+            throw std::runtime_error("Failed to isolate the roots");
+        }
+        giac::vecteur intervals;
+        for (const giac::gen &root : *roots._VECTptr) {
+            giac::gen interval = root._VECTptr->front();
+            if (interval.type == giac::_VECT) {
+                intervals.push_back(interval);
+            } else {
+                intervals.push_back(giac::makevecteur(interval, interval));
+            }
+        }
+        *res = giac::gen(intervals);
+    });
+}
+
+extern "C" result giacrs_gen_sqrfree(giac::gen *e, giac::gen *var,
+                                     giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_sqrfree(giac::makesequence(*e, *var), ctx));
+}
+
+// Returns the coefficients (highest degree first) of the characteristic
+// polynomial of a(x) in Q(x), where x is a root of minpoly
+static giac::vecteur field_charpoly(const giac::gen &a,
+                                    const giac::gen &minpoly,
+                                    giac::context *ctx) {
+    giac::gen vars = giac::_lvar(minpoly, ctx);
+    if (vars.type != giac::_VECT || vars._VECTptr->size() != 1) {
+        throw std::runtime_error("Minimal polynomial must be univariate");
+    }
+    giac::gen x = vars._VECTptr->front();
+    giac::gen a_vars = giac::_lvar(a, ctx);
+    for (const giac::gen &v : *a_vars._VECTptr) {
+        if (v != x) {
+            throw std::runtime_error(
+                "Expression is not in the field defined by the minimal "
+                "polynomial");
+        }
+    }
+    giac::gen y(giac::identificateur("giacrs_field_y"));
+    // res_x(minpoly(x), y-a(x)) = prod(y-a(conjugates))
+    giac::gen charpoly =
+        giac::_resultant(giac::makesequence(minpoly, y - a, x), ctx);
+    giac::gen coeffs = giac::_symb2poly(giac::makesequence(charpoly, y), ctx);
+    if (coeffs.type != giac::_VECT || coeffs._VECTptr->size() < 2) {
+        throw std::runtime_error(
+            "Failed to compute the characteristic polynomial");
+    }
+    return *coeffs._VECTptr;
+}
+
+extern "C" result giacrs_gen_field_norm(giac::gen *a, giac::gen *minpoly,
+                                        giac::gen *res, giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur c = field_charpoly(*a, *minpoly, ctx);
+        size_t n = c.size() - 1;
+        *res = (n % 2 == 0 ? c.back() : -c.back()) / c.front();
+    });
+}
+
+extern "C" result giacrs_gen_field_trace(giac::gen *a, giac::gen *minpoly,
+                                         giac::gen *res, giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur c = field_charpoly(*a, *minpoly, ctx);
+        *res = -c[1] / c[0];
+    });
+}
+
+extern "C" result giacrs_gen_thiele(giac::gen *xs, giac::gen *ys,
+                                    giac::gen *var, giac::gen *res,
+                                    giac::context *ctx) {
+    SAFE_VOID_CALL({
+        *res = giac::_thiele(giac::makesequence(*xs, *ys, *var), ctx);
+        if (giac::is_undef(*res)) {
+            throw std::runtime_error("No rational interpolant exists");
+        }
+    });
+}
+
+extern "C" result giacrs_gen_hermite_interp(giac::gen *nodes,
+                                            giac::gen *values,
+                                            giac::gen *derivs, giac::gen *var,
+                                            giac::gen *res,
+                                            giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (nodes->type != giac::_VECT || values->type != giac::_VECT ||
+            derivs->type != giac::_VECT) {
+            throw std::runtime_error(
+                "Nodes, values and derivatives must be vectors");
+        }
+        const giac::vecteur &x = *nodes->_VECTptr, &f = *values->_VECTptr,
+                            &df = *derivs->_VECTptr;
+        size_t n = x.size();
+        if (f.size() != n || df.size() != n) {
+            throw std::runtime_error(
+                "Nodes, values and derivatives must have the same length");
+        }
+        // Divided differences on the doubled nodes z = [x0,x0,x1,x1,...]
+        size_t m = 2 * n;
+        giac::vecteur z(m);
+        std::vector<giac::vecteur> q(m, giac::vecteur(m, 0));
+        for (size_t i = 0; i < n; i++) {
+            z[2 * i] = z[2 * i + 1] = x[i];
+            q[2 * i][0] = q[2 * i + 1][0] = f[i];
+            q[2 * i + 1][1] = df[i];
+            if (i > 0) {
+                giac::gen dx = x[i] - x[i - 1];
+                if (giac::is_zero(dx, ctx)) {
+                    throw std::runtime_error("Nodes must be pairwise distinct");
+                }
+                q[2 * i][1] = (f[i] - f[i - 1]) / dx;
+            }
+        }
+        for (size_t j = 2; j < m; j++) {
+            for (size_t i = j; i < m; i++) {
+                giac::gen dz = z[i] - z[i - j];
+                if (giac::is_zero(dz, ctx)) {
+                    throw std::runtime_error("Nodes must be pairwise distinct");
+                }
+                q[i][j] = (q[i][j - 1] - q[i - 1][j - 1]) / dz;
+            }
+        }
+        // Newton form of the polynomial
+        giac::gen p(0), basis(1);
+        for (size_t k = 0; k < m; k++) {
+            p = p + q[k][k] * basis;
+            basis = basis * (*var - z[k]);
+        }
+        *res = giac::_normal(p, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_symmetric_poly(unsigned int k, giac::gen *vars,
+                                            giac::gen *res,
+                                            giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (vars->type != giac::_VECT) {
+            throw std::runtime_error("Variables must be a vector");
+        }
+        const giac::vecteur &v = *vars->_VECTptr;
+        if (k > v.size()) {
+            throw std::runtime_error("k exceeds the number of variables");
+        }
+        // e[j] is the jth elementary symmetric polynomial of the variables
+        // seen so far
+        giac::vecteur e(k + 1, 0);
+        e[0] = 1;
+        for (const giac::gen &var : v) {
+            for (unsigned int j = k; j > 0; j--) {
+                e[j] = e[j] + var * e[j - 1];
+            }
+        }
+        *res = giac::_expand(e[k], ctx);
+    });
+}
+
+extern "C" result giacrs_gen_laurent(giac::gen *e, giac::gen *var,
+                                     giac::gen *at, unsigned int order,
+                                     giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_series(giac::makesequence(*e, giac::symb_equal(*var, *at),
+                                               giac::gen(int(order))),
+                            ctx));
+}
+
+extern "C" result giacrs_gen_residue(giac::gen *e, giac::gen *var,
+                                     giac::gen *pole, giac::gen *res,
+                                     giac::context *ctx) {
+    SAFE_CALL(giac::_residue(giac::makesequence(*e, *var, *pole), ctx));
+}
+
+extern "C" result giacrs_gen_sum(giac::gen *e, giac::gen *index,
+                                 giac::gen *lower, giac::gen *upper,
+                                 giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::_sum(giac::makesequence(*e, *index, *lower, *upper), ctx));
+}
+
+extern "C" result giacrs_gen_product(giac::gen *e, giac::gen *index,
+                                     giac::gen *lower, giac::gen *upper,
+                                     giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(
+        giac::_product(giac::makesequence(*e, *index, *lower, *upper), ctx));
+}
+
+extern "C" result giacrs_gen_plot_points(giac::gen *e, giac::gen *var,
+                                        double from, double to, size_t samples,
+                                        double *xs, double *ys,
+                                        giac::context *ctx) {
+    SAFE_VOID_CALL({
+        for (size_t i = 0; i < samples; i++) {
+            double x = samples == 1 ? from
+                                    : from + (to - from) * i / (samples - 1);
+            double y = NAN;
+            try {
+                giac::gen value = giac::evalf_double(
+                    giac::subst(*e, *var, giac::gen(x), false, ctx), 1, ctx);
+                if (value.type == giac::_DOUBLE_ &&
+                    std::isfinite(value._DOUBLE_val)) {
+                    y = value._DOUBLE_val;
+                }
+            } catch (std::runtime_error &) {
+                // The point is skipped, y stays NaN
+            }
+            xs[i] = x;
+            ys[i] = y;
+        }
+    });
+}
+
+extern "C" result giacrs_gen_accelerate_sequence(giac::gen *terms,
+                                                 giac::gen *res,
+                                                 giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (terms->type != giac::_VECT || terms->_VECTptr->size() < 2) {
+            throw std::runtime_error("At least 2 terms are required");
+        }
+        const giac::vecteur &s = *terms->_VECTptr;
+        int n = s.size();
+        int m = std::min(n - 1, 8);
+        // Richardson extrapolation of order m from S_N, ..., S_{N+m}:
+        // sum(S_{N+k}*(N+k)^m*(-1)^(k+m)/(k!*(m-k)!), k=0..m)
+        int first = n - m;
+        giac::gen sum = 0;
+        for (int k = 0; k <= m; k++) {
+            giac::gen c = giac::pow(giac::gen(first + k), m, ctx) /
+                          (giac::factorial(k) * giac::factorial(m - k));
+            if ((k + m) % 2 == 1) {
+                c = -c;
+            }
+            sum = sum + c * s[first + k - 1];
+        }
+        *res = giac::normal(sum, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_apply(const char *func, giac::gen *e,
+                                   giac::gen **args, size_t n, giac::gen *res,
+                                   giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen f(std::string(func), ctx);
+        if (giac::first_error_line(ctx) != 0) {
+            return string_to_c(giac::parser_error(ctx));
+        }
+        if (n == 0) {
+            *res = f(*e, ctx);
+        } else {
+            giac::vecteur v(1, *e);
+            for (size_t i = 0; i < n; i++) {
+                v.push_back(*args[i]);
+            }
+            *res = f(giac::gen(v, giac::_SEQ__VECT), ctx);
+        }
+    });
+}
+
+extern "C" result giacrs_gen_pow(giac::gen *base, giac::gen *exponent,
+                                 giac::gen *res, giac::context *ctx) {
+    SAFE_CALL(giac::pow(*base, *exponent, ctx));
+}
+
+// OPERATOR
+
+extern "C" result giacrs_gen_add(giac::gen *res, giac::gen *f) {
+    SAFE_CALL(*res + *f)
+}
+extern "C" result giacrs_gen_sub(giac::gen *res, giac::gen *f) {
+    SAFE_CALL(*res - *f)
+}
+extern "C" result giacrs_gen_mul(giac::gen *res, giac::gen *f) {
+    SAFE_CALL(*res * *f)
+}
+extern "C" result giacrs_gen_div(giac::gen *res, giac::gen *f) {
+    SAFE_CALL(*res / *f)
+}
+
+// Giac pads the shorter vector with zeros when adding vectors of different
+// sizes, which would hide dimension errors. Coefficient lists of
+// polynomials are padded on purpose, so they aren't checked.
+static void check_same_shape(const giac::gen &a, const giac::gen &b) {
+    if (a.type != giac::_VECT || b.type != giac::_VECT ||
+        a.subtype == giac::_POLY1__VECT || b.subtype == giac::_POLY1__VECT) {
+        return;
+    }
+    if (a._VECTptr->size() != b._VECTptr->size()) {
+        throw std::runtime_error("Dimensions must agree");
+    }
+    for (size_t i = 0; i < a._VECTptr->size(); i++) {
+        check_same_shape((*a._VECTptr)[i], (*b._VECTptr)[i]);
+    }
+}
+
+extern "C" result giacrs_gen_checked_add(giac::gen *res, giac::gen *f) {
+    SAFE_VOID_CALL({
+        check_same_shape(*res, *f);
+        *res = *res + *f;
+    });
+}
+extern "C" result giacrs_gen_checked_sub(giac::gen *res, giac::gen *f) {
+    SAFE_VOID_CALL({
+        check_same_shape(*res, *f);
+        *res = *res - *f;
+    });
+}
+
+// OTHERS
+
+extern "C" result giacrs_gen_factor(giac::gen *e, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_CALL(giac::_factor(*e, ctx));
+}
+
+enum function_property_kind { EVEN = 0, ODD = 1, PERIODIC = 2 };
+
+struct function_property {
+    giac::gen name;
+    int kind;
+    giac::gen period;
+};
+
+// Whether an expression is written with a leading minus sign, like -2,
+// -x, -2*x or -x+1
+static bool has_minus_sign(const giac::gen &e, const giac::context *ctx) {
+    if (e.type == giac::_INT_ || e.type == giac::_ZINT ||
+        e.type == giac::_DOUBLE_ || e.type == giac::_REAL ||
+        e.type == giac::_FRAC) {
+        return giac::is_strictly_positive(-e, ctx);
+    }
+    if (e.type != giac::_SYMB) {
+        return false;
+    }
+    if (e.is_symb_of_sommet(giac::at_neg)) {
+        return true;
+    }
+    const giac::gen &args = e._SYMBptr->feuille;
+    if ((e.is_symb_of_sommet(giac::at_prod) ||
+         e.is_symb_of_sommet(giac::at_plus)) &&
+        args.type == giac::_VECT && !args._VECTptr->empty()) {
+        return has_minus_sign(args._VECTptr->front(), ctx);
+    }
+    return false;
+}
+
+// Removes the terms of a sum which are integer multiples of the period
+static giac::gen reduce_period(const giac::gen &x, const giac::gen &period,
+                               const giac::context *ctx) {
+    giac::vecteur terms(1, x);
+    if (x.is_symb_of_sommet(giac::at_plus) &&
+        x._SYMBptr->feuille.type == giac::_VECT) {
+        terms = *x._SYMBptr->feuille._VECTptr;
+    }
+    giac::gen res(0);
+    for (const giac::gen &t : terms) {
+        if (!giac::is_integer(giac::normal(t / period, ctx))) {
+            res = res + t;
+        }
+    }
+    return res;
+}
+
+// Rewrites the calls f(x) of the functions with properties, so that
+// equivalent calls are written the same way
+static giac::gen
+apply_function_properties(const giac::gen &e,
+                          const std::vector<function_property> &props,
+                          const giac::context *ctx) {
+    if (e.type == giac::_VECT) {
+        giac::vecteur v;
+        for (const giac::gen &x : *e._VECTptr) {
+            v.push_back(apply_function_properties(x, props, ctx));
+        }
+        return giac::gen(v, e.subtype);
+    }
+    if (e.type != giac::_SYMB) {
+        return e;
+    }
+    giac::gen args = apply_function_properties(e._SYMBptr->feuille, props, ctx);
+    if (!e.is_symb_of_sommet(giac::at_of) || args.type != giac::_VECT ||
+        args._VECTptr->size() != 2) {
+        return giac::symbolic(e._SYMBptr->sommet, args);
+    }
+    giac::gen f = args._VECTptr->front(), x = args._VECTptr->back();
+    // Only functions of a single variable
+    if (x.type == giac::_VECT && x.subtype == giac::_SEQ__VECT) {
+        return giac::symb_of(f, x);
+    }
+    bool negate = false;
+    for (const function_property &p : props) {
+        if (f != p.name) {
+            continue;
+        }
+        if (p.kind == PERIODIC) {
+            x = reduce_period(x, p.period, ctx);
+        } else if (has_minus_sign(x, ctx)) {
+            x = giac::normal(-x, ctx);
+            negate = negate != (p.kind == ODD);
+        }
+    }
+    giac::gen res = giac::symb_of(f, x);
+    return negate ? -res : res;
+}
+
+extern "C" result giacrs_gen_simplify(giac::gen *e, const char **names,
+                                      const int *kinds, const char **periods,
+                                      size_t n, giac::gen *res,
+                                      const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        std::vector<function_property> props(n);
+        // Periodicity is applied first, so that the sign is normalized on
+        // the reduced argument
+        for (size_t i = 0; i < n; i++) {
+            props[i].name = giac::gen(giac::identificateur(names[i]));
+            props[i].kind = kinds[i];
+            if (kinds[i] == PERIODIC) {
+                props[i].period = giac::gen(std::string(periods[i]), ctx);
+            }
+        }
+        std::stable_partition(props.begin(), props.end(),
+                              [](const function_property &p) {
+                                  return p.kind == PERIODIC;
+                              });
+        *res = giac::simplify(
+            n == 0 ? *e : apply_function_properties(*e, props, ctx), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_normal(giac::gen *e, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_CALL(giac::_normal(*e, ctx));
+}
+
+extern "C" result giacrs_gen_partial_eval(giac::gen *e, giac::gen **vars,
+                                          giac::gen **values, size_t n,
+                                          giac::gen *res,
+                                          const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur v(n), w(n);
+        for (size_t i = 0; i < n; i++) {
+            v[i] = *vars[i];
+            w[i] = *values[i];
+        }
+        *res = giac::normal(giac::subst(*e, v, w, false, ctx), ctx);
+    });
+}
+
+// Whether an identifier is a pattern variable, like x_
+static bool is_pattern_variable(const giac::gen &e) {
+    if (e.type != giac::_IDNT) {
+        return false;
+    }
+    std::string name = e._IDNTptr->id_name;
+    return name.size() > 1 && name.back() == '_';
+}
+
+// Matches an expression against a pattern, binding the pattern variables.
+// A variable appearing several times must match equal subexpressions.
+static bool match_pattern(const giac::gen &pattern, const giac::gen &e,
+                          giac::vecteur &vars, giac::vecteur &values) {
+    if (is_pattern_variable(pattern)) {
+        for (size_t i = 0; i < vars.size(); i++) {
+            if (vars[i] == pattern) {
+                return values[i] == e;
+            }
+        }
+        vars.push_back(pattern);
+        values.push_back(e);
+        return true;
+    }
+    if (pattern.type == giac::_SYMB) {
+        return e.type == giac::_SYMB &&
+               pattern._SYMBptr->sommet == e._SYMBptr->sommet &&
+               match_pattern(pattern._SYMBptr->feuille, e._SYMBptr->feuille,
+                             vars, values);
+    }
+    if (pattern.type == giac::_VECT) {
+        if (e.type != giac::_VECT || e.subtype != pattern.subtype ||
+            e._VECTptr->size() != pattern._VECTptr->size()) {
+            return false;
+        }
+        for (size_t i = 0; i < e._VECTptr->size(); i++) {
+            if (!match_pattern((*pattern._VECTptr)[i], (*e._VECTptr)[i], vars,
+                               values)) {
+                return false;
+            }
+        }
+        return true;
+    }
+    return pattern == e;
+}
+
+// Replaces, from the root, the subexpressions matching a pattern. A
+// replaced subexpression isn't walked again, so rules can't loop.
+static giac::gen rewrite(const giac::gen &e, const giac::vecteur &patterns,
+                         const giac::vecteur &replacements,
+                         const giac::context *ctx) {
+    for (size_t i = 0; i < patterns.size(); i++) {
+        giac::vecteur vars, values;
+        if (match_pattern(patterns[i], e, vars, values)) {
+            return vars.empty() ? replacements[i]
+                                : giac::subst(replacements[i], vars, values,
+                                              false, ctx);
+        }
+    }
+    if (e.type == giac::_VECT) {
+        giac::vecteur v;
+        for (const giac::gen &x : *e._VECTptr) {
+            v.push_back(rewrite(x, patterns, replacements, ctx));
+        }
+        return giac::gen(v, e.subtype);
+    }
+    if (e.type != giac::_SYMB) {
+        return e;
+    }
+    // The operands are rewritten one by one, the sequence itself isn't an
+    // expression to match
+    giac::vecteur args;
+    for (const giac::gen &x : operands(e)) {
+        args.push_back(rewrite(x, patterns, replacements, ctx));
+    }
+    return giac::symbolic(e._SYMBptr->sommet,
+                          args.size() == 1
+                              ? args.front()
+                              : giac::gen(args, giac::_SEQ__VECT));
+}
+
+extern "C" result giacrs_gen_rewrite(giac::gen *e, giac::gen **patterns,
+                                     giac::gen **replacements, size_t n,
+                                     giac::gen *res,
+                                     const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::vecteur p, r;
+        for (size_t i = 0; i < n; i++) {
+            p.push_back(*patterns[i]);
+            r.push_back(*replacements[i]);
+        }
+        *res = giac::eval(rewrite(*e, p, r, ctx), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_simplify_factorials(giac::gen *e, giac::gen *res,
+                                                 const giac::context *ctx) {
+    SAFE_CALL(giac::_normal(giac::_texpand(*e, ctx), ctx));
+}
+
+extern "C" result giacrs_gen_tlin(giac::gen *e, giac::gen *res,
+                                  const giac::context *ctx) {
+    SAFE_CALL(giac::_tlin(*e, ctx));
+}
+
+// Converts a propositional formula to negation normal form, where negations
+// only apply to variables
+static giac::gen logic_nnf(const giac::gen &e, bool negate) {
+    std::string op = operator_name(e);
+    giac::vecteur args = operands(e);
+    if (op == "not") {
+        return logic_nnf(args.front(), !negate);
+    }
+    if (op == "xor") {
+        // a xor b = (a and not b) or (not a and b)
+        giac::gen res = args[0];
+        for (size_t i = 1; i < args.size(); i++) {
+            res = giac::symb_ou(giac::symb_and(res, giac::symb_not(args[i])),
+                                giac::symb_and(giac::symb_not(res), args[i]));
+        }
+        return logic_nnf(res, negate);
+    }
+    if (op == "and" || op == "or") {
+        // De Morgan's laws
+        bool is_and = (op == "and") != negate;
+        giac::gen res = logic_nnf(args[0], negate);
+        for (size_t i = 1; i < args.size(); i++) {
+            giac::gen arg = logic_nnf(args[i], negate);
+            res = is_and ? giac::symb_and(res, arg) : giac::symb_ou(res, arg);
+        }
+        return res;
+    }
+    if (e.type == giac::_IDNT) {
+        return negate ? giac::symb_not(e) : e;
+    }
+    if (e.type == giac::_INT_ && e.subtype == giac::_INT_BOOLEAN) {
+        return giac::gen(giac::is_zero(e) == negate, giac::_INT_BOOLEAN);
+    }
+    throw std::runtime_error(
+        "Expression must only contain and, or, not, xor and identifiers");
+}
+
+// Writes a formula in negation normal form as a polynomial, where `inner`
+// (and for DNF, or for CNF) is a product and the other connective a sum.
+// Literals are kept as atoms, the neutral element of `inner` becomes 1 and
+// the other constant 0.
+static giac::gen logic_to_poly(const giac::gen &e, const std::string &inner) {
+    std::string op = operator_name(e);
+    if (e.type == giac::_INT_) {
+        return giac::gen(int(giac::is_zero(e) != (inner == "and")));
+    }
+    if (op != "and" && op != "or") {
+        return e;
+    }
+    giac::vecteur args = operands(e);
+    giac::gen res = logic_to_poly(args[0], inner);
+    for (size_t i = 1; i < args.size(); i++) {
+        giac::gen arg = logic_to_poly(args[i], inner);
+        res = op == inner ? res * arg : res + arg;
+    }
+    return res;
+}
+
+// Joins literals or clauses with a connective, an empty list giving its
+// neutral element
+static giac::gen logic_join(const giac::vecteur &args, bool is_and) {
+    if (args.empty()) {
+        return giac::gen(is_and, giac::_INT_BOOLEAN);
+    }
+    giac::gen res = args[0];
+    for (size_t i = 1; i < args.size(); i++) {
+        res = is_and ? giac::symb_and(res, args[i])
+                     : giac::symb_ou(res, args[i]);
+    }
+    return res;
+}
+
+// Giac's expand distributes the products over the sums, each monomial of
+// the result is a clause. Coefficients and exponents only come from
+// repeated literals or clauses, so they are dropped.
+static giac::gen logic_normal_form(const giac::gen &e, bool cnf,
+                                   const giac::context *ctx) {
+    giac::gen poly = giac::_expand(
+        logic_to_poly(logic_nnf(e, false), cnf ? "or" : "and"), ctx);
+    giac::vecteur terms = operator_name(poly) == "+" ? operands(poly)
+                                                     : giac::vecteur(1, poly);
+    giac::vecteur clauses;
+    for (const giac::gen &term : terms) {
+        if (giac::is_zero(term, ctx)) {
+            continue;
+        }
+        giac::vecteur factors = operator_name(term) == "*"
+                                    ? operands(term)
+                                    : giac::vecteur(1, term);
+        giac::vecteur literals;
+        for (const giac::gen &factor : factors) {
+            if (operator_name(factor) == "^") {
+                literals.push_back(operands(factor).front());
+            } else if (!giac::is_integer(factor)) {
+                literals.push_back(factor);
+            }
+        }
+        clauses.push_back(logic_join(literals, !cnf));
+    }
+    return logic_join(clauses, cnf);
+}
+
+extern "C" result giacrs_gen_to_dnf(giac::gen *e, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_CALL(logic_normal_form(*e, false, ctx));
+}
+
+extern "C" result giacrs_gen_to_cnf(giac::gen *e, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_CALL(logic_normal_form(*e, true, ctx));
+}
+
+extern "C" result giacrs_gen_evalc(giac::gen *e, giac::gen *res,
+                                   const giac::context *ctx) {
+    SAFE_CALL(giac::_evalc(*e, ctx));
+}
+
+extern "C" result giacrs_gen_identity(size_t n, giac::gen *res,
+                                      const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (n == 0) {
+            throw std::runtime_error("Matrix size must be positive");
+        }
+        *res = giac::_idn(giac::gen(int(n)), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_filled_matrix(size_t rows, size_t cols,
+                                           int value, giac::gen *res,
+                                           const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (rows == 0 || cols == 0) {
+            throw std::runtime_error("Matrix size must be positive");
+        }
+        // Each row is allocated separately, since giac may modify vectors in
+        // place
+        giac::matrice m;
+        for (size_t i = 0; i < rows; i++) {
+            m.push_back(giac::vecteur(cols, value));
+        }
+        *res = giac::gen(m, giac::_MATRIX__VECT);
+    });
+}
+
+extern "C" result giacrs_gen_matpow(giac::gen *e, int64_t n, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_squarematrix(*e)) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        if (n < 0 && giac::is_zero(giac::_det(*e, ctx))) {
+            throw std::runtime_error("Matrix is not invertible");
+        }
+        // giac's ^ is the matrix power on square matrices
+        *res = giac::_pow(giac::makesequence(*e, giac::gen((long long)n)), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_lu(giac::gen *e, giac::gen *p, giac::gen *l,
+                                giac::gen *u, const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_squarematrix(*e)) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        giac::gen res = giac::_lu(*e, ctx);
+        if (res.type != giac::_VECT || res._VECTptr->size() < 3) {
+            throw std::runtime_error("Failed to compute the LU decomposition");
+        }
+        const giac::vecteur &v = *res._VECTptr;
+        // The permutation is returned as a list of indices
+        *p = giac::_permu2mat(v[0], ctx);
+        *l = v[1];
+        *u = v[2];
+    });
+}
+
+extern "C" result giacrs_gen_qr(giac::gen *e, giac::gen *q, giac::gen *r,
+                                const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*e)) {
+            throw std::runtime_error("Expression is not a matrix");
+        }
+        giac::gen res = giac::_qr(*e, ctx);
+        if (res.type != giac::_VECT || res._VECTptr->size() < 2) {
+            throw std::runtime_error("Failed to compute the QR decomposition");
+        }
+        *q = (*res._VECTptr)[0];
+        *r = (*res._VECTptr)[1];
+    });
+}
+
+extern "C" result giacrs_gen_det(giac::gen *e, giac::gen *res,
+                                 const giac::context *ctx) {
+    SAFE_CALL(giac::_det(*e, ctx));
+}
+
+// Cofactor of the entry (i, j) of a square matrix
+extern "C" result giacrs_gen_cofactor(giac::gen *e, size_t i, size_t j,
+                                      giac::gen *res,
+                                      const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_squarematrix(*e)) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        if (i >= e->_VECTptr->size() || j >= e->_VECTptr->size()) {
+            throw std::runtime_error("Index out of range");
+        }
+        // comatrix is the matrix of cofactors
+        *res = giac::_comatrix(*e, ctx)[int(i)][int(j)];
+    });
+}
+
+extern "C" result giacrs_gen_adjoint(giac::gen *e, giac::gen *res,
+                                     const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_squarematrix(*e)) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        *res = giac::_tran(giac::_comatrix(*e, ctx), ctx);
+    });
+}
+
+// Returns the non-zero entries of a matrix as [row, column, value] vectors
+extern "C" result giacrs_gen_sparse_entries(giac::gen *e, giac::gen *res,
+                                            const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*e)) {
+            throw std::runtime_error("Expression is not a matrix");
+        }
+        const giac::matrice &m = *e->_VECTptr;
+        giac::vecteur entries;
+        for (size_t i = 0; i < m.size(); i++) {
+            const giac::vecteur &row = *m[i]._VECTptr;
+            for (size_t j = 0; j < row.size(); j++) {
+                if (!giac::is_zero(row[j], ctx)) {
+                    entries.push_back(
+                        giac::makevecteur(int(i), int(j), row[j]));
+                }
+            }
+        }
+        *res = entries;
+    });
+}
+
+extern "C" result giacrs_gen_transpose(giac::gen *e, giac::gen *res,
+                                       const giac::context *ctx) {
+    SAFE_CALL(giac::_tran(*e, ctx));
+}
+
+// Checks that a and b are vectors of the same length
+static void check_same_length(const giac::gen &a, const giac::gen &b) {
+    if (a.type != giac::_VECT || b.type != giac::_VECT || giac::ckmatrix(a) ||
+        giac::ckmatrix(b)) {
+        throw std::runtime_error("Arguments must be vectors");
+    }
+    if (a._VECTptr->size() != b._VECTptr->size()) {
+        throw std::runtime_error("Vectors must have the same length");
+    }
+}
+
+extern "C" result giacrs_gen_dot(giac::gen *a, giac::gen *b, giac::gen *res,
+                                 const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        check_same_length(*a, *b);
+        *res = giac::_dot(giac::makesequence(*a, *b), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_cross(giac::gen *a, giac::gen *b, giac::gen *res,
+                                   const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        check_same_length(*a, *b);
+        if (a->_VECTptr->size() != 3) {
+            throw std::runtime_error("Vectors must have 3 coordinates");
+        }
+        *res = giac::_cross(giac::makesequence(*a, *b), ctx);
+    });
+}
+
+static void check_vector_or_matrix(const giac::gen &e) {
+    if (e.type != giac::_VECT) {
+        throw std::runtime_error("Expression is not a vector or a matrix");
+    }
+}
+
+extern "C" result giacrs_gen_norm(giac::gen *e, giac::gen *res,
+                                  const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        check_vector_or_matrix(*e);
+        *res = giac::ckmatrix(*e) ? giac::_frobenius_norm(*e, ctx)
+                                  : giac::_l2norm(*e, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_norm_p(giac::gen *e, unsigned int p,
+                                    giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        check_vector_or_matrix(*e);
+        if (p == 0) {
+            throw std::runtime_error("p must be at least 1");
+        }
+        if (giac::ckmatrix(*e)) {
+            if (p != 1) {
+                throw std::runtime_error(
+                    "Only the 1-norm is supported for matrices");
+            }
+            *res = giac::_colnorm(*e, ctx);
+        } else if (p == 1) {
+            *res = giac::_l1norm(*e, ctx);
+        } else if (p == 2) {
+            *res = giac::_l2norm(*e, ctx);
+        } else {
+            giac::gen sum = 0;
+            for (const giac::gen &x : *e->_VECTptr) {
+                sum = sum + giac::pow(giac::abs(x, ctx), int(p), ctx);
+            }
+            *res = giac::pow(sum, giac::gen(1) / giac::gen(int(p)), ctx);
+        }
+    });
+}
+
+extern "C" result giacrs_gen_norm_inf(giac::gen *e, giac::gen *res,
+                                      const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        check_vector_or_matrix(*e);
+        *res = giac::ckmatrix(*e) ? giac::_rownorm(*e, ctx)
+                                  : giac::_maxnorm(*e, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_hconcat(giac::gen *a, giac::gen *b,
+                                     giac::gen *res,
+                                     const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*a) || !giac::ckmatrix(*b)) {
+            throw std::runtime_error("Arguments must be matrices");
+        }
+        const giac::matrice &ma = *a->_VECTptr, &mb = *b->_VECTptr;
+        if (ma.size() != mb.size()) {
+            throw std::runtime_error(
+                "Matrices must have the same number of rows");
+        }
+        giac::matrice m;
+        for (size_t i = 0; i < ma.size(); i++) {
+            giac::vecteur row(*ma[i]._VECTptr);
+            row.insert(row.end(), mb[i]._VECTptr->begin(),
+                       mb[i]._VECTptr->end());
+            m.push_back(row);
+        }
+        *res = giac::gen(m, giac::_MATRIX__VECT);
+    });
+}
+
+extern "C" result giacrs_gen_vconcat(giac::gen *a, giac::gen *b,
+                                     giac::gen *res,
+                                     const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*a) || !giac::ckmatrix(*b)) {
+            throw std::runtime_error("Arguments must be matrices");
+        }
+        const giac::matrice &ma = *a->_VECTptr, &mb = *b->_VECTptr;
+        if (ma[0]._VECTptr->size() != mb[0]._VECTptr->size()) {
+            throw std::runtime_error(
+                "Matrices must have the same number of columns");
+        }
+        giac::matrice m(ma);
+        m.insert(m.end(), mb.begin(), mb.end());
+        *res = giac::gen(m, giac::_MATRIX__VECT);
+    });
+}
+
+extern "C" result giacrs_gen_submatrix(giac::gen *e, size_t row_start,
+                                       size_t row_end, size_t col_start,
+                                       size_t col_end, giac::gen *res,
+                                       const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*e)) {
+            throw std::runtime_error("Expression is not a matrix");
+        }
+        const giac::matrice &m = *e->_VECTptr;
+        if (row_start >= row_end || col_start >= col_end) {
+            throw std::runtime_error("Ranges must not be empty");
+        }
+        if (row_end > m.size() || col_end > m[0]._VECTptr->size()) {
+            throw std::runtime_error("Range out of the matrix bounds");
+        }
+        giac::matrice sub;
+        for (size_t i = row_start; i < row_end; i++) {
+            const giac::vecteur &row = *m[i]._VECTptr;
+            sub.push_back(giac::vecteur(row.begin() + col_start,
+                                        row.begin() + col_end));
+        }
+        *res = giac::gen(sub, giac::_MATRIX__VECT);
+    });
+}
+
+extern "C" result giacrs_gen_trace(giac::gen *e, giac::gen *res,
+                                   const giac::context *ctx) {
+    SAFE_CALL(giac::_trace(*e, ctx));
+}
+
+extern "C" result giacrs_gen_rank(giac::gen *e, giac::gen *res,
+                                  const giac::context *ctx) {
+    SAFE_CALL(giac::_rank(*e, ctx));
+}
+
+// Rounds each entry of a matrix to the nearest integer, returns false if an
+// entry isn't within epsilon of an integer
+static bool round_matrix(const giac::gen &e, giac::gen &res,
+                         const giac::context *ctx) {
+    if (!giac::ckmatrix(e)) {
+        throw std::runtime_error("Expression is not a matrix");
+    }
+    giac::gen eps = giac::epsilon(ctx);
+    giac::matrice rounded;
+    for (const giac::gen &row : *e._VECTptr) {
+        giac::vecteur r;
+        for (const giac::gen &x : *row._VECTptr) {
+            giac::gen value = giac::evalf(x, 1, ctx);
+            giac::gen n = giac::_round(value, ctx);
+            if (!giac::is_integer(n) ||
+                giac::is_strictly_greater(giac::abs(value - n, ctx), eps,
+                                          ctx)) {
+                return false;
+            }
+            r.push_back(n);
+        }
+        rounded.push_back(r);
+    }
+    res = giac::gen(rounded, giac::_MATRIX__VECT);
+    return true;
+}
+
+extern "C" result giacrs_gen_is_integer_matrix(giac::gen *e, bool *res,
+                                               const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen rounded;
+        *res = giac::ckmatrix(*e) && round_matrix(*e, rounded, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_round_matrix_to_integer(giac::gen *e,
+                                                     giac::gen *res,
+                                                     const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!round_matrix(*e, *res, ctx)) {
+            throw std::runtime_error("Matrix has non-integer entries");
+        }
+    });
+}
+
+// Maps each entry of a (nested) vector to a modular number in Z/pZ
+giac::gen to_modular(const giac::gen &e, const giac::gen &p) {
+    if (e.type != giac::_VECT) {
+        return giac::makemod(e, p);
+    }
+    giac::vecteur v;
+    for (const giac::gen &x : *e._VECTptr) {
+        v.push_back(to_modular(x, p));
+    }
+    return giac::gen(v, e.subtype);
+}
+
+extern "C" result giacrs_gen_rank_mod(giac::gen *e, unsigned int p,
+                                      int64_t *res,
+                                      const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (p > (unsigned int)INT32_MAX) {
+            throw std::runtime_error("Modulus is too large");
+        }
+        giac::gen modulus(int(p));
+        if (giac::is_zero(giac::_isprime(modulus, ctx))) {
+            throw std::runtime_error("Modulus must be prime");
+        }
+        *res = giac::_rank(to_modular(*e, modulus), ctx).to_int();
+    });
+}
+
+extern "C" result giacrs_gen_rref(giac::gen *e, giac::gen *res,
+                                  const giac::context *ctx) {
+    SAFE_CALL(giac::_rref(*e, ctx));
+}
+
+extern "C" result giacrs_gen_kernel(giac::gen *e, giac::gen *res,
+                                    const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*e)) {
+            throw std::runtime_error("Expression is not a matrix");
+        }
+        *res = giac::_ker(*e, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_image(giac::gen *e, giac::gen *res,
+                                   const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::ckmatrix(*e)) {
+            throw std::runtime_error("Expression is not a matrix");
+        }
+        *res = giac::_image(*e, ctx);
+    });
+}
+
+extern "C" result giacrs_gen_definiteness(giac::gen *e, uint8_t *res,
+                                          const giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (!giac::is_zero(*e - giac::_tran(*e, ctx), ctx)) {
+            throw std::runtime_error("Matrix must be symmetric");
+        }
+        giac::gen eigenvalues = giac::_eigenvals(*e, ctx);
+        if (eigenvalues.type != giac::_VECT) {
+            throw std::runtime_error("Failed to compute eigenvalues");
+        }
+        int positive = 0, negative = 0, zero = 0;
+        for (const giac::gen &lambda : *eigenvalues._VECTptr) {
+            giac::gen sign = giac::_sign(lambda, ctx);
+            if (sign.type != giac::_INT_) {
+                throw std::runtime_error(
+                    "Cannot determine the sign of an eigenvalue");
+            }
+            if (sign.val > 0) {
+                positive++;
+            } else if (sign.val < 0) {
+                negative++;
+            } else {
+                zero++;
+            }
+        }
+        if (positive > 0 && negative > 0) {
+            *res = 4;
+        } else if (negative > 0) {
+            *res = zero > 0 ? 3 : 2;
+        } else {
+            *res = zero > 0 ? 1 : 0;
+        }
+    });
+}
+
+extern "C" result giacrs_gen_charpoly(giac::gen *e, giac::gen *var,
+                                      giac::gen *res,
+                                      const giac::context *ctx) {
+    SAFE_CALL(giac::_pcar(giac::makesequence(*e, *var), ctx));
+}
+
+extern "C" result giacrs_gen_charpoly_coeffs(giac::gen *e, giac::gen *res,
+                                             const giac::context *ctx) {
+    SAFE_CALL(giac::_pcar(*e, ctx));
+}
+
+extern "C" result giacrs_gen_apply_matrix_function(const char *func,
+                                                   giac::gen *e,
+                                                   giac::gen *res,
+                                                   giac::context *ctx) {
+    SAFE_VOID_CALL({
+        giac::gen f(std::string(func), ctx);
+        if (giac::first_error_line(ctx) != 0) {
+            return string_to_c(giac::parser_error(ctx));
+        }
+        if (!giac::is_squarematrix(*e)) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        // A = P*D*P^-1, with D diagonal
+        giac::gen p = giac::_egv(*e, ctx);
+        giac::gen d = giac::_egvl(*e, ctx);
+        if (!giac::is_squarematrix(p) || giac::is_zero(giac::_det(p, ctx))) {
+            throw std::runtime_error("Matrix is not diagonalizable");
+        }
+        size_t n = d._VECTptr->size();
+        giac::matrice fd;
+        for (size_t i = 0; i < n; i++) {
+            giac::vecteur row(n, 0);
+            row[i] = f((*(*d._VECTptr)[i]._VECTptr)[i], ctx);
+            fd.push_back(row);
+        }
+        *res = giac::_simplify(p * giac::gen(fd) * giac::inv(p, ctx), ctx);
+    });
+}
+
+extern "C" result giacrs_gen_dominant_eigenvalue(giac::gen *e,
+                                                 unsigned int iterations,
+                                                 double *res,
+                                                 giac::context *ctx) {
+    SAFE_VOID_CALL({
+        if (iterations == 0) {
+            throw std::runtime_error("At least one iteration is needed");
+        }
+        if (!giac::is_squarematrix(*e) || e->_VECTptr->empty()) {
+            throw std::runtime_error("Expression is not a square matrix");
+        }
+        const giac::matrice &m = *e->_VECTptr;
+        size_t n = m.size();
+        std::vector<std::vector<double>> a(n, std::vector<double>(n));
+        for (size_t i = 0; i < n; i++) {
+            for (size_t j = 0; j < n; j++) {
+                giac::gen d = giac::evalf_double((*m[i]._VECTptr)[j], 1, ctx);
+                if (d.type != giac::_DOUBLE_) {
+                    throw std::runtime_error("Matrix must be numeric");
+                }
+                a[i][j] = d._DOUBLE_val;
+            }
+        }
+        // Power method, the eigenvalue is estimated by the Rayleigh quotient
+        std::vector<double> v(n, 1 / std::sqrt(double(n))), w(n);
+        double lambda = 0;
+        for (unsigned int k = 0; k < iterations; k++) {
+            for (size_t i = 0; i < n; i++) {
+                w[i] = 0;
+                for (size_t j = 0; j < n; j++) {
+                    w[i] += a[i][j] * v[j];
+                }
+            }
+            double dot = 0, norm = 0;
+            for (size_t i = 0; i < n; i++) {
+                dot += v[i] * w[i];
+                norm += w[i] * w[i];
+            }
+            lambda = dot;
+            norm = std::sqrt(norm);
+            if (norm == 0) {
+                break;
+            }
+            for (size_t i = 0; i < n; i++) {
+                v[i] = w[i] / norm;
+            }
+        }
+        *res = lambda;
+    });
 }
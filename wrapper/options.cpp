@@ -1,7 +1,38 @@
 #include "utils.hpp"
+#include <giac/gen.h>
 #include <giac/global.h>
+#include <giac/usual.h>
 
 extern "C" void giacrs_options_set_epsilon(double e,
                                              const giac::context *ctx) {
     giac::epsilon(e, ctx);
 }
+
+extern "C" void giacrs_options_set_digits(unsigned int digits,
+                                          const giac::context *ctx) {
+    giac::set_decimal_digits(digits, ctx);
+}
+
+extern "C" void giacrs_options_set_approx_mode(bool on,
+                                               const giac::context *ctx) {
+    giac::approx_mode(on, ctx);
+}
+
+extern "C" void giacrs_options_set_complex_mode(bool on,
+                                                const giac::context *ctx) {
+    giac::complex_mode(on, ctx);
+}
+
+extern "C" void giacrs_options_set_angle_radian(bool radian,
+                                                const giac::context *ctx) {
+    giac::angle_radian(radian, ctx);
+}
+
+extern "C" void giacrs_options_set_random_seed(unsigned long long seed,
+                                               const giac::context *ctx) {
+    // giac seeds are ints, fold the seed to 31 bits
+    int folded = int((seed ^ (seed >> 32)) & 0x7fffffff);
+    // Only the seed stored in the context, unlike _srand which also reseeds
+    // the global C generator
+    giac::rand_seed(folded, ctx);
+}
@@ -1,4 +1,5 @@
 #include <ostream>
+#include <string>
 
 #define SAFE_VOID_CALL(res_code)                                               \
     try {                                                                      \
@@ -14,6 +15,9 @@
 
 typedef const char *result;
 
+// Copies a string to a C string, which must be freed by the caller
+char *string_to_c(std::string s);
+
 // This is a stream which does not output anything
 class NullStream : public std::ostream {
     // streambuffer doing nothing